@@ -0,0 +1,179 @@
+use std::{collections::BTreeMap, fmt::{Display, Write}};
+
+use crate::fof9_weekdata::{GamePlayInfo9, Week9Data};
+
+const EXPLOSIVE_RUN_YARDS: i32 = 10;
+const EXPLOSIVE_PASS_YARDS: i32 = 20;
+
+
+/// Outcomes of a group of defended plays
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PlayOutcomes9 {
+    pub plays: u32,
+    pub yards: i32,
+    pub pass_attempts: u32,  // sacks not included
+    pub completions: u32,
+    pub sacks: u32,
+    pub explosive: u32,
+    pub turnovers: u32,
+}
+
+impl PlayOutcomes9 {
+    pub fn add_play ( &mut self, info: &GamePlayInfo9 ) {
+        let Some(result) = info.play.play_result() else { return };
+        self.plays += 1;
+        self.yards += result.yards_gained();
+        if result.sack() { self.sacks += 1; }
+        if result.is_pass_attempt() {
+            self.pass_attempts += 1;
+            if result.complete() { self.completions += 1; }
+        }
+        let explosive = if result.is_rush() { EXPLOSIVE_RUN_YARDS } else { EXPLOSIVE_PASS_YARDS };
+        if result.yards_gained() >= explosive { self.explosive += 1; }
+        if result.is_turnover() { self.turnovers += 1; }
+    }
+
+    pub fn combine ( &mut self, other: &PlayOutcomes9 ) {
+        self.plays += other.plays;
+        self.yards += other.yards;
+        self.pass_attempts += other.pass_attempts;
+        self.completions += other.completions;
+        self.sacks += other.sacks;
+        self.explosive += other.explosive;
+        self.turnovers += other.turnovers;
+    }
+
+    pub fn yards_per_play ( &self ) -> f64 {
+        if self.plays == 0 { 0.0 } else { f64::from(self.yards) / f64::from(self.plays) }
+    }
+
+    pub fn completion_pct ( &self ) -> f64 {
+        if self.pass_attempts == 0 { 0.0 } else { 100.0 * f64::from(self.completions) / f64::from(self.pass_attempts) }
+    }
+
+    /// Sacks per dropback
+    pub fn sack_pct ( &self ) -> f64 {
+        let dropbacks = self.pass_attempts + self.sacks;
+        if dropbacks == 0 { 0.0 } else { 100.0 * f64::from(self.sacks) / f64::from(dropbacks) }
+    }
+
+    pub fn explosive_pct ( &self ) -> f64 {
+        if self.plays == 0 { 0.0 } else { 100.0 * f64::from(self.explosive) / f64::from(self.plays) }
+    }
+}
+
+impl Display for PlayOutcomes9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:>6} plays {:>5.2} Y/P {:>5.1}% comp {:>5.1}% sack {:>5.1}% explosive {:>4} TO",
+            self.plays, self.yards_per_play(), self.completion_pct(), self.sack_pct(), self.explosive_pct(), self.turnovers)
+    }
+}
+
+/// Play outcomes split by blitz count, blitzing position and spy, for one defense or the whole league
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct BlitzAnalysis9 {
+    pub team: Option<u32>,  // league team number, None league-wide
+    pub all: PlayOutcomes9,
+    pub by_blitz_count: BTreeMap<usize, PlayOutcomes9>,
+    pub by_position: BTreeMap<String, PlayOutcomes9>,  // as blitz_position names it
+    pub spy: PlayOutcomes9,
+    pub no_spy: PlayOutcomes9,
+}
+
+impl BlitzAnalysis9 {
+    pub fn league ( weeks: &[Week9Data] ) -> Self {
+        let mut analysis = BlitzAnalysis9::default();
+        for game in weeks.iter().flat_map(|week| week.games.iter()) {
+            for info in game.plays() {
+                analysis.add_play(&info);
+            }
+        }
+        analysis
+    }
+
+    pub fn defense ( weeks: &[Week9Data], team: u32 ) -> Self {
+        let mut analysis = BlitzAnalysis9 { team: Some(team), ..Default::default() };
+        for game in weeks.iter().flat_map(|week| week.games.iter()) {
+            for info in game.plays().filter(|info| game.team(info.def_team()).number() == team) {
+                analysis.add_play(&info);
+            }
+        }
+        analysis
+    }
+
+    /// One analysis per defense, by league team number
+    pub fn by_defense ( weeks: &[Week9Data] ) -> BTreeMap<u32, Self> {
+        let mut analyses = BTreeMap::<u32, Self>::new();
+        for game in weeks.iter().flat_map(|week| week.games.iter()) {
+            for info in game.plays() {
+                let team = game.team(info.def_team()).number();
+                analyses.entry(team).or_insert_with(|| BlitzAnalysis9 { team: Some(team), ..Default::default() }).add_play(&info);
+            }
+        }
+        analyses
+    }
+
+    pub fn add_play ( &mut self, info: &GamePlayInfo9 ) {
+        let Some(formation) = info.play.formation() else { return };
+        let blitzers = info.play.defensive_blitzers();
+
+        self.all.add_play(info);
+        self.by_blitz_count.entry(blitzers.len()).or_default().add_play(info);
+        for &blitzer in blitzers {
+            self.by_position.entry(formation.blitz_position(blitzer)).or_default().add_play(info);
+        }
+        if info.play.defensive_spies().is_empty() {
+            self.no_spy.add_play(info);
+        } else {
+            self.spy.add_play(info);
+        }
+    }
+
+    fn team_name ( &self ) -> String {
+        match self.team {
+            Some(team) => format!("team {}", team),
+            None => "league".to_string(),
+        }
+    }
+
+    pub fn to_text ( &self ) -> String {
+        let mut out = format!("Blitz and spy outcomes, {}\n{:<14}{}\n", self.team_name(), "All", self.all);
+        let _ = writeln!(out, "By blitzers");
+        for (count, outcomes) in &self.by_blitz_count {
+            let _ = writeln!(out, "  {:<12}{}", count, outcomes);
+        }
+        let _ = writeln!(out, "By blitzing position");
+        for (position, outcomes) in &self.by_position {
+            let _ = writeln!(out, "  {:<12}{}", position, outcomes);
+        }
+        let _ = writeln!(out, "Spy\n  {:<12}{}\n  {:<12}{}", "Spy", self.spy, "No Spy", self.no_spy);
+        out
+    }
+
+    /// One row per group, with a header row
+    pub fn to_csv ( &self ) -> String {
+        let mut out = String::from("team,split,group,plays,yards,yards_per_play,pass_attempts,completion_pct,sacks,sack_pct,explosive,explosive_pct,turnovers\n");
+        let team = self.team.map(|team| team.to_string()).unwrap_or_else(|| "league".to_string());
+        let mut row = |split: &str, group: &str, o: &PlayOutcomes9| {
+            let _ = writeln!(out, "{},{},{},{},{},{:.2},{},{:.1},{},{:.1},{},{:.1},{}", team, split, group, o.plays, o.yards, o.yards_per_play(),
+                o.pass_attempts, o.completion_pct(), o.sacks, o.sack_pct(), o.explosive, o.explosive_pct(), o.turnovers);
+        };
+
+        row("all", "all", &self.all);
+        for (count, outcomes) in &self.by_blitz_count {
+            row("blitzers", &count.to_string(), outcomes);
+        }
+        for (position, outcomes) in &self.by_position {
+            row("position", position, outcomes);
+        }
+        row("spy", "spy", &self.spy);
+        row("spy", "no spy", &self.no_spy);
+        out
+    }
+}
+
+impl Display for BlitzAnalysis9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
@@ -0,0 +1,300 @@
+use std::{collections::BTreeMap, fmt::{Display, Write}};
+
+use crate::{fof9_penalty::PenaltyOutcome9, fof9_playerdata::Players9Data, fof9_playresult::KickType9, fof9_scoring::{play_scores, tries}, fof9_weekdata::Game9Data};
+
+type TeamStat9 = fn(&TeamBox9) -> String;
+
+#[derive(Debug, Clone, Default)]
+pub struct TeamBox9 {
+    pub name: String,
+    pub short: String,
+    pub score_by_quarter: Vec<u32>,  // overtime periods after the fourth
+    pub first_downs: u32,
+    pub pass_attempts: u32,
+    pub pass_completions: u32,
+    pub pass_yards: i32,
+    pub rush_attempts: u32,
+    pub rush_yards: i32,
+    pub turnovers: u32,
+    pub penalties: u32,
+    pub penalty_yards: u32,
+    pub time_of_possession: u32,  // seconds
+    pub extra_points_made: u32,
+    pub extra_points_attempted: u32,
+}
+
+impl TeamBox9 {
+    pub fn score ( &self ) -> u32 {
+        self.score_by_quarter.iter().sum()
+    }
+
+    pub fn total_yards ( &self ) -> i32 {
+        self.pass_yards + self.rush_yards
+    }
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct PassingLine9 {
+    pub attempts: u32,
+    pub completions: u32,
+    pub yards: i32,
+    pub touchdowns: u32,
+    pub interceptions: u32,
+    pub sacks: u32,
+    pub sack_yards: i32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct RushingLine9 {
+    pub attempts: u32,
+    pub yards: i32,
+    pub touchdowns: u32,
+    pub long: i32,
+    pub fumbles: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct ReceivingLine9 {
+    pub targets: u32,
+    pub receptions: u32,
+    pub yards: i32,
+    pub touchdowns: u32,
+    pub long: i32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DefensiveLine9 {
+    pub tackles: u32,
+    pub assists: u32,
+    pub sacks: u32,
+    pub interceptions: u32,
+}
+
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct KickingLine9 {
+    pub field_goals_made: u32,
+    pub field_goals_attempted: u32,
+    pub long: u32,
+    pub punts: u32,
+    pub punt_yards: u32,
+}
+
+/// Individual lines for one team, by player id
+#[derive(Debug, Clone, Default)]
+pub struct PlayerLines9 {
+    pub passing: BTreeMap<u32, PassingLine9>,
+    pub rushing: BTreeMap<u32, RushingLine9>,
+    pub receiving: BTreeMap<u32, ReceivingLine9>,
+    pub defense: BTreeMap<u32, DefensiveLine9>,
+    pub kicking: BTreeMap<u32, KickingLine9>,
+}
+
+/// Team and player totals for a game; index 0 is the home team, 1 the away team
+#[derive(Debug, Clone)]
+pub struct BoxScore9 {
+    pub teams: [TeamBox9; 2],
+    pub players: [PlayerLines9; 2],
+    pub player_of_game: u32,
+}
+
+impl BoxScore9 {
+    /// Team yardage and time of possession come from the game file's own totals. First downs, turnovers and the
+    /// player lines are built from the play decode, which has not been checked against a known game (see PlayResult9
+    /// and KickResult9). Two point tries are left out of both.
+    pub fn new ( game: &Game9Data ) -> Self {
+        let mut teams = [TeamBox9::default(), TeamBox9::default()];
+        let mut players = [PlayerLines9::default(), PlayerLines9::default()];
+
+        for (index, team) in teams.iter_mut().enumerate() {
+            let game_team = index as u32;
+            team.name = format!("{} {}", game.team(game_team).city(), game.team(game_team).name());
+            team.short = game.team(game_team).short();
+            team.score_by_quarter = vec![0; 4];
+
+            // team yardage comes from the game's own totals
+            let passing = game.pass_stats(game_team).total();
+            team.pass_attempts = passing.attempts();
+            team.pass_completions = passing.completions();
+            team.pass_yards = passing.yards();
+            let rushing = game.run_stats(game_team).total();
+            team.rush_attempts = rushing.attempts();
+            team.rush_yards = rushing.yards();
+            team.time_of_possession = game.possession_stats(game_team).seconds();
+        }
+
+        let plays: Vec<_> = game.plays().collect();
+        for ((info, is_try), play_score) in plays.iter().zip(tries(&plays)).zip(play_scores(&plays)) {
+            let off = info.off_team as usize;
+            let def = info.def_team() as usize;
+
+            if let Some((team, score_type)) = play_score {
+                let quarter = (info.quarter.max(1) - 1) as usize;
+                for t in teams.iter_mut() {
+                    if t.score_by_quarter.len() <= quarter { t.score_by_quarter.resize(quarter + 1, 0); }
+                }
+                teams[team as usize].score_by_quarter[quarter] += score_type.points();
+            }
+
+            if let Some(extra_point) = info.play.extra_point() {
+                if extra_point.is_kick() {
+                    teams[off].extra_points_attempted += 1;
+                    if extra_point.points() > 0 { teams[off].extra_points_made += 1; }
+                }
+            }
+
+            if let Some(result) = info.play.play_result().filter(|_| !is_try) {
+                if result.first_down() { teams[off].first_downs += 1; }
+                if result.is_turnover() { teams[off].turnovers += 1; }
+
+                let touchdown = result.touchdown() && !result.is_turnover();
+                if let Some(passer) = result.passer() {
+                    let line = players[off].passing.entry(passer).or_default();
+                    if result.sack() {
+                        line.sacks += 1;
+                        line.sack_yards += result.yards_gained();
+                    } else if result.is_pass_attempt() {
+                        line.attempts += 1;
+                        if result.complete() {
+                            line.completions += 1;
+                            line.yards += result.yards_gained();
+                        }
+                        if result.interception() { line.interceptions += 1; }
+                        if touchdown { line.touchdowns += 1; }
+                    }
+                }
+
+                if let Some(carrier) = result.ball_carrier() {
+                    if result.is_rush() {
+                        let line = players[off].rushing.entry(carrier).or_default();
+                        line.attempts += 1;
+                        line.yards += result.yards_gained();
+                        line.long = line.long.max(result.yards_gained());
+                        if touchdown { line.touchdowns += 1; }
+                        if result.fumble() { line.fumbles += 1; }
+                    }
+                }
+
+                if let Some(target) = result.target() {
+                    let line = players[off].receiving.entry(target).or_default();
+                    line.targets += 1;
+                    if result.complete() {
+                        line.receptions += 1;
+                        line.yards += result.yards_gained();
+                        line.long = line.long.max(result.yards_gained());
+                        if touchdown { line.touchdowns += 1; }
+                    }
+                }
+
+                if let Some(tackler) = result.tackler() {
+                    let line = players[def].defense.entry(tackler).or_default();
+                    line.tackles += 1;
+                    if result.sack() { line.sacks += 1; }
+                }
+                if let Some(assist) = result.assist_tackler() {
+                    players[def].defense.entry(assist).or_default().assists += 1;
+                }
+                if let Some(interceptor) = result.intercepted_by() {
+                    players[def].defense.entry(interceptor).or_default().interceptions += 1;
+                }
+            }
+
+            if let Some(kick) = info.play.kick_result() {
+                if kick.is_turnover(info.off_team) { teams[def].turnovers += 1; }
+
+                match kick.kick_type() {
+                    KickType9::FieldGoal => {
+                        let line = players[off].kicking.entry(kick.kicker()).or_default();
+                        line.field_goals_attempted += 1;
+                        if kick.field_goal().is_some_and(|fg| fg.is_good()) {
+                            line.field_goals_made += 1;
+                            line.long = line.long.max(kick.distance());
+                        }
+                    },
+
+                    KickType9::Punt => {
+                        let line = players[off].kicking.entry(kick.kicker()).or_default();
+                        line.punts += 1;
+                        line.punt_yards += kick.distance();
+                    },
+
+                    _ => {},
+                }
+            }
+        }
+
+        // declined flags don't count against a team, nor do flags whose outcome can't be told
+        for penalty in game.penalties().iter().filter(|p| p.outcome == PenaltyOutcome9::Accepted) {
+            let team = &mut teams[penalty.game_team as usize];
+            team.penalties += 1;
+            team.penalty_yards += penalty.yards;
+        }
+
+        BoxScore9 { teams, players, player_of_game: game.player_of_game() }
+    }
+
+    pub fn home ( &self ) -> &TeamBox9 {
+        &self.teams[0]
+    }
+
+    pub fn away ( &self ) -> &TeamBox9 {
+        &self.teams[1]
+    }
+
+    /// A text box score, with player names looked up where players are given
+    pub fn render ( &self, players: Option<&Players9Data> ) -> String {
+        let name = |id: u32| match players.and_then(|p| p.player_data(id)) {
+            Some(player) => player.name(),
+            None => format!("#{}", id),
+        };
+        let mut out = String::new();
+
+        let periods = self.teams[0].score_by_quarter.len();
+        let _ = writeln!(out, "{:<6}{}  {:>3}", "",
+            (1..=periods).map(|q| format!("{:>3}", if q > 4 { "OT".to_string() } else { q.to_string() })).collect::<String>(), "T");
+        for team in &self.teams {
+            let _ = writeln!(out, "{:<6}{}  {:>3}", team.short,
+                team.score_by_quarter.iter().map(|p| format!("{:>3}", p)).collect::<String>(), team.score());
+        }
+
+        let _ = writeln!(out, "\n{:<20}{:>8}{:>8}", "", self.teams[0].short, self.teams[1].short);
+        let rows: [(&str, TeamStat9); 7] = [
+            ("First downs", |t| t.first_downs.to_string()),
+            ("Passing", |t| format!("{}-{}-{}", t.pass_completions, t.pass_attempts, t.pass_yards)),
+            ("Rushing", |t| format!("{}-{}", t.rush_attempts, t.rush_yards)),
+            ("Total yards", |t| t.total_yards().to_string()),
+            ("Turnovers", |t| t.turnovers.to_string()),
+            ("Penalties", |t| format!("{}-{}", t.penalties, t.penalty_yards)),
+            ("Possession", |t| format!("{}:{:02}", t.time_of_possession / 60, t.time_of_possession % 60)),
+        ];
+        for (label, value) in rows {
+            let _ = writeln!(out, "{:<20}{:>8}{:>8}", label, value(&self.teams[0]), value(&self.teams[1]));
+        }
+
+        for (team, lines) in self.teams.iter().zip(self.players.iter()) {
+            let _ = writeln!(out, "\n{}", team.name);
+            for (id, l) in &lines.passing {
+                let _ = writeln!(out, "  Passing    {:<24} {}/{}, {} yds, {} td, {} int, {} sk", name(*id), l.completions, l.attempts, l.yards, l.touchdowns, l.interceptions, l.sacks);
+            }
+            for (id, l) in &lines.rushing {
+                let _ = writeln!(out, "  Rushing    {:<24} {} att, {} yds, {} td, long {}", name(*id), l.attempts, l.yards, l.touchdowns, l.long);
+            }
+            for (id, l) in &lines.receiving {
+                let _ = writeln!(out, "  Receiving  {:<24} {} rec ({} tgt), {} yds, {} td, long {}", name(*id), l.receptions, l.targets, l.yards, l.touchdowns, l.long);
+            }
+            for (id, l) in &lines.defense {
+                let _ = writeln!(out, "  Defense    {:<24} {} tkl, {} ast, {} sk, {} int", name(*id), l.tackles, l.assists, l.sacks, l.interceptions);
+            }
+            for (id, l) in &lines.kicking {
+                let _ = writeln!(out, "  Kicking    {:<24} {}/{} fg, long {}, {} punts for {} yds", name(*id), l.field_goals_made, l.field_goals_attempted, l.long, l.punts, l.punt_yards);
+            }
+            let _ = writeln!(out, "  Extra points {}/{}", team.extra_points_made, team.extra_points_attempted);
+        }
+        out
+    }
+}
+
+impl Display for BoxScore9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.render(None))
+    }
+}
@@ -0,0 +1,225 @@
+use std::fmt::Display;
+
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive;
+
+use crate::fof9_weekdata::{DriveInfo9, Game9Data};
+
+const QUARTER_SECONDS: u32 = 15 * 60;
+
+
+// codes are guesses from the order the game lists drive results, unverified until the drive_results test in
+// load_week has been run against a league; it checks each against the last play of its drives, and unknown codes
+// stay raw in Drive9::result_code
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, FromPrimitive)]
+pub enum DriveResult9 {
+    Touchdown = 1,  // ?
+    FieldGoal = 2,  // ?
+    MissedFieldGoal = 3,  // ?
+    Punt = 4,  // ?
+    Fumble = 5,  // ?
+    Interception = 6,  // ?
+    Downs = 7,  // ?
+    Safety = 8,  // ?
+    EndOfHalf = 9,  // ?
+    EndOfGame = 10,  // ?
+}
+
+impl DriveResult9 {
+    pub fn is_score ( &self ) -> bool {
+        matches!(self, DriveResult9::Touchdown | DriveResult9::FieldGoal)
+    }
+
+    /// Fumbles and interceptions
+    pub fn is_turnover ( &self ) -> bool {
+        matches!(self, DriveResult9::Fumble | DriveResult9::Interception)
+    }
+
+    pub fn is_turnover_on_downs ( &self ) -> bool {
+        matches!(self, DriveResult9::Downs)
+    }
+
+    /// Points for the offense, not counting the try after a touchdown
+    pub fn points ( &self ) -> u32 {
+        match self {
+            DriveResult9::Touchdown => 6,
+            DriveResult9::FieldGoal => 3,
+            _ => 0,
+        }
+    }
+}
+
+impl Display for DriveResult9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            DriveResult9::Touchdown => "Touchdown",
+            DriveResult9::FieldGoal => "Field Goal",
+            DriveResult9::MissedFieldGoal => "Missed FG",
+            DriveResult9::Punt => "Punt",
+            DriveResult9::Fumble => "Fumble",
+            DriveResult9::Interception => "Interception",
+            DriveResult9::Downs => "Downs",
+            DriveResult9::Safety => "Safety",
+            DriveResult9::EndOfHalf => "End of Half",
+            DriveResult9::EndOfGame => "End of Game",
+        })
+    }
+}
+
+/// Game time, as the game shows it
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub struct GameClock9 {
+    pub quarter: u32,
+    pub minutes: u32,
+    pub seconds: u32,
+}
+
+impl GameClock9 {
+    /// Seconds since kickoff, with overtime periods counted as full quarters
+    pub fn elapsed ( &self ) -> u32 {
+        self.quarter.saturating_sub(1) * QUARTER_SECONDS + QUARTER_SECONDS.saturating_sub(self.minutes * 60 + self.seconds)
+    }
+}
+
+impl Display for GameClock9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}Q {}:{:02}", self.quarter, self.minutes, self.seconds)
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Drive9 {
+    pub team: u32,  // 0 = home, 1 = away
+    pub start: GameClock9,
+    pub end: GameClock9,
+    pub start_yards_from_goal: u32,
+    pub start_yardline: String,  // as field_yardline shows it
+    pub plays: u32,
+    pub yards: i32,
+    pub result: Option<DriveResult9>,
+    pub result_code: u32,
+}
+
+impl Drive9 {
+    fn new ( game: &Game9Data, team: u32, info: &DriveInfo9 ) -> Self {
+        // field_yardline counts from the away goal line
+        let yards = if team == 0 { info.start_yards_from_goal } else { 100u32.saturating_sub(info.start_yards_from_goal) };
+
+        Drive9 {
+            team,
+            start: GameClock9 { quarter: info.start_quarter, minutes: info.start_minutes, seconds: info.start_seconds },
+            end: GameClock9 { quarter: info.end_quarter, minutes: info.end_minutes, seconds: info.end_seconds },
+            start_yards_from_goal: info.start_yards_from_goal,
+            start_yardline: game.field_yardline(yards),
+            plays: info.num_plays,
+            yards: info.yards_gained,
+            result: DriveResult9::from_u32(info.result),
+            result_code: info.result,
+        }
+    }
+
+    /// Game seconds the drive took
+    pub fn duration ( &self ) -> u32 {
+        self.end.elapsed().saturating_sub(self.start.elapsed())
+    }
+
+    pub fn points ( &self ) -> u32 {
+        self.result.map(|result| result.points()).unwrap_or_default()
+    }
+}
+
+impl Display for Drive9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let duration = self.duration();
+        write!(f, "{} {:<6} {:>2} plays {:>3} yds {:>2}:{:02}  {}", self.start, self.start_yardline, self.plays, self.yards,
+            duration / 60, duration % 60,
+            match self.result {
+                Some(result) => result.to_string(),
+                None => format!("<result {}>", self.result_code),
+            })
+    }
+}
+
+/// Drive totals for one team in one or more games
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct DriveSummary9 {
+    pub drives: u32,
+    pub plays: u32,
+    pub yards: i32,
+    pub seconds: u32,
+    pub start_yards_from_goal: u32,  // summed, for the average
+    pub touchdowns: u32,
+    pub field_goals: u32,
+    pub punts: u32,
+    pub turnovers: u32,
+    pub turnovers_on_downs: u32,
+}
+
+impl DriveSummary9 {
+    pub fn add ( &mut self, drive: &Drive9 ) {
+        self.drives += 1;
+        self.plays += drive.plays;
+        self.yards += drive.yards;
+        self.seconds += drive.duration();
+        self.start_yards_from_goal += drive.start_yards_from_goal;
+        match drive.result {
+            Some(DriveResult9::Touchdown) => self.touchdowns += 1,
+            Some(DriveResult9::FieldGoal) => self.field_goals += 1,
+            Some(DriveResult9::Punt) => self.punts += 1,
+            Some(result) if result.is_turnover() => self.turnovers += 1,
+            Some(result) if result.is_turnover_on_downs() => self.turnovers_on_downs += 1,
+            _ => {},
+        }
+    }
+
+    pub fn combine ( &mut self, other: &DriveSummary9 ) {
+        self.drives += other.drives;
+        self.plays += other.plays;
+        self.yards += other.yards;
+        self.seconds += other.seconds;
+        self.start_yards_from_goal += other.start_yards_from_goal;
+        self.touchdowns += other.touchdowns;
+        self.field_goals += other.field_goals;
+        self.punts += other.punts;
+        self.turnovers += other.turnovers;
+        self.turnovers_on_downs += other.turnovers_on_downs;
+    }
+
+    pub fn yards_per_drive ( &self ) -> f64 {
+        if self.drives == 0 { 0.0 } else { f64::from(self.yards) / f64::from(self.drives) }
+    }
+
+    pub fn plays_per_drive ( &self ) -> f64 {
+        if self.drives == 0 { 0.0 } else { f64::from(self.plays) / f64::from(self.drives) }
+    }
+
+    /// Average start, in yards from the goal line being attacked
+    pub fn average_start ( &self ) -> f64 {
+        if self.drives == 0 { 0.0 } else { f64::from(self.start_yards_from_goal) / f64::from(self.drives) }
+    }
+
+    /// Share of drives ending in a touchdown or field goal
+    pub fn scoring_rate ( &self ) -> f64 {
+        if self.drives == 0 { 0.0 } else { f64::from(self.touchdowns + self.field_goals) / f64::from(self.drives) }
+    }
+}
+
+impl Display for DriveSummary9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} drives, {:.1} plays, {:.1} yds per drive, start {:.1} from goal, {} td, {} fg, {} punts, {} turnovers, {} on downs",
+            self.drives, self.plays_per_drive(), self.yards_per_drive(), self.average_start(),
+            self.touchdowns, self.field_goals, self.punts, self.turnovers, self.turnovers_on_downs)
+    }
+}
+
+pub(crate) fn drives ( game: &Game9Data, game_team: u32 ) -> Vec<Drive9> {
+    game.drive_info(game_team).iter().map(|info| Drive9::new(game, game_team, info)).collect()
+}
+
+pub(crate) fn drive_summary ( game: &Game9Data, game_team: u32 ) -> DriveSummary9 {
+    let mut summary = DriveSummary9::default();
+    for drive in game.drives(game_team) {
+        summary.add(&drive);
+    }
+    summary
+}
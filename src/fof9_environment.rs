@@ -0,0 +1,278 @@
+use std::{collections::BTreeMap, fmt::{Display, Write}};
+
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive;
+
+use crate::{fof9_utility::csv_field, fof9_weekdata::{Attendance9, Game9Data, Game9Section, Week9Data}};
+
+const WEEKDAYS: [&str; 7] = ["Sunday", "Monday", "Tuesday", "Wednesday", "Thursday", "Friday", "Saturday"];
+
+
+// only sunny and partly cloudy have been confirmed
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, FromPrimitive)]
+pub enum Weather9 {
+    Sunny = 0,
+    PartlyCloudy = 1,
+    Cloudy = 2,  // ?
+    Rain = 3,  // ?
+    Snow = 4,  // ?
+    Dome = 5,  // ?
+}
+
+impl Display for Weather9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Weather9::Sunny => "Sunny",
+            Weather9::PartlyCloudy => "Partly Cloudy",
+            Weather9::Cloudy => "Cloudy",
+            Weather9::Rain => "Rain",
+            Weather9::Snow => "Snow",
+            Weather9::Dome => "Dome",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum SeatingSection9 {
+    UpperDeck,
+    EndZone,
+    Mezzanine,
+    Sidelines,
+    Club,
+    Boxes,
+}
+
+impl SeatingSection9 {
+    pub const ALL: [SeatingSection9; 6] = [SeatingSection9::UpperDeck, SeatingSection9::EndZone, SeatingSection9::Mezzanine,
+        SeatingSection9::Sidelines, SeatingSection9::Club, SeatingSection9::Boxes];
+}
+
+impl Display for SeatingSection9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            SeatingSection9::UpperDeck => "Upper Deck",
+            SeatingSection9::EndZone => "End Zone",
+            SeatingSection9::Mezzanine => "Mezzanine",
+            SeatingSection9::Sidelines => "Sidelines",
+            SeatingSection9::Club => "Club",
+            SeatingSection9::Boxes => "Boxes",
+        })
+    }
+}
+
+/// The location string split at its first comma, as in "Stadium, City"
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameLocation9 {
+    pub stadium: String,
+    pub city: Option<String>,
+}
+
+impl GameLocation9 {
+    pub fn parse ( location: &str ) -> Self {
+        match location.split_once(',') {
+            Some((stadium, city)) => GameLocation9 { stadium: stadium.trim().to_string(), city: Some(city.trim().to_string()) },
+            None => GameLocation9 { stadium: location.trim().to_string(), city: None },
+        }
+    }
+}
+
+impl Display for GameLocation9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match &self.city {
+            Some(city) => write!(f, "{}, {}", self.stadium, city),
+            None => write!(f, "{}", self.stadium),
+        }
+    }
+}
+
+/// The when string split into its comma separated parts; any part may be missing
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameTime9 {
+    pub weekday: Option<String>,
+    pub date: Option<String>,
+    pub kickoff: Option<String>,  // the part with a clock time
+}
+
+impl GameTime9 {
+    pub fn parse ( when: &str ) -> Self {
+        let mut time = GameTime9 { weekday: None, date: None, kickoff: None };
+        let mut date = Vec::new();
+
+        for part in when.split(',').map(str::trim).filter(|part| !part.is_empty()) {
+            if time.weekday.is_none() && WEEKDAYS.iter().any(|day| part.eq_ignore_ascii_case(day)) {
+                time.weekday = Some(part.to_string());
+            } else if time.kickoff.is_none() && part.contains(':') {
+                time.kickoff = Some(part.to_string());
+            } else {
+                date.push(part);
+            }
+        }
+        if !date.is_empty() {
+            time.date = Some(date.join(", "));
+        }
+        time
+    }
+}
+
+/// Weather and crowd at kickoff
+#[derive(Debug, Clone, PartialEq)]
+pub struct GameEnvironment9 {
+    pub location: GameLocation9,
+    pub when: GameTime9,
+    pub location_text: String,
+    pub when_text: String,
+    pub weather: Option<Weather9>,
+    pub weather_code: u32,
+    pub temperature: u32,  // degrees F
+    pub wind_mph: u32,
+    pub attendance: u32,
+    pub no_shows: u32,
+    pub capacity: u32,
+    pub sections: [(SeatingSection9, Attendance9); 6],
+}
+
+impl GameEnvironment9 {
+    /// Percent of capacity in attendance
+    pub fn sell_through ( &self ) -> f64 {
+        if self.capacity == 0 { 0.0 } else { 100.0 * f64::from(self.attendance) / f64::from(self.capacity) }
+    }
+
+    pub fn section ( &self, section: SeatingSection9 ) -> Attendance9 {
+        self.sections.iter().find(|(s, _)| *s == section).map(|(_, attendance)| *attendance).unwrap_or_default()
+    }
+}
+
+impl Display for GameEnvironment9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}: {}, {}F, wind {} mph, attendance {} of {} ({:.1}%)", self.when_text, self.location,
+            match self.weather {
+                Some(weather) => weather.to_string(),
+                None => format!("<weather {}>", self.weather_code),
+            },
+            self.temperature, self.wind_mph, self.attendance, self.capacity, self.sell_through())
+    }
+}
+
+pub(crate) fn environment ( game: &Game9Data ) -> GameEnvironment9 {
+    if let Game9Section::Start { location, when, total_attendance, no_shows, upperdeck, endzone, mezzanine, sidelines, club, boxes,
+        starting_temperature, starting_weather, total_capacity, windspeed_mph, .. } = game.sections.first().unwrap() {
+        GameEnvironment9 {
+            location: GameLocation9::parse(&location.string),
+            when: GameTime9::parse(&when.string),
+            location_text: location.to_string(),
+            when_text: when.to_string(),
+            weather: Weather9::from_u32(*starting_weather),
+            weather_code: *starting_weather,
+            temperature: *starting_temperature,
+            wind_mph: *windspeed_mph,
+            attendance: *total_attendance,
+            no_shows: *no_shows,
+            capacity: *total_capacity,
+            sections: [
+                (SeatingSection9::UpperDeck, *upperdeck),
+                (SeatingSection9::EndZone, *endzone),
+                (SeatingSection9::Mezzanine, *mezzanine),
+                (SeatingSection9::Sidelines, *sidelines),
+                (SeatingSection9::Club, *club),
+                (SeatingSection9::Boxes, *boxes),
+            ],
+        }
+    } else {
+        panic!("first section of game is not start");
+    }
+}
+
+/// Attendance totals for one stadium
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct StadiumAttendance9 {
+    pub stadium: String,
+    pub home_teams: Vec<u32>,  // league team numbers
+    pub games: u32,
+    pub attendance: u32,
+    pub no_shows: u32,
+    pub capacity: u32,
+    pub sections: BTreeMap<SeatingSection9, Attendance9>,
+}
+
+impl StadiumAttendance9 {
+    pub fn average_attendance ( &self ) -> f64 {
+        if self.games == 0 { 0.0 } else { f64::from(self.attendance) / f64::from(self.games) }
+    }
+
+    pub fn sell_through ( &self ) -> f64 {
+        if self.capacity == 0 { 0.0 } else { 100.0 * f64::from(self.attendance) / f64::from(self.capacity) }
+    }
+}
+
+/// Attendance by stadium over a set of weeks
+#[derive(Debug, Clone, Default)]
+pub struct AttendanceReport9 {
+    pub stadiums: BTreeMap<String, StadiumAttendance9>,
+}
+
+impl AttendanceReport9 {
+    pub fn new ( weeks: &[Week9Data] ) -> Self {
+        let mut report = AttendanceReport9::default();
+        for game in weeks.iter().flat_map(|week| week.games.iter()) {
+            report.add_game(game);
+        }
+        report
+    }
+
+    pub fn add_game ( &mut self, game: &Game9Data ) {
+        let environment = game.environment();
+        let stadium = self.stadiums.entry(environment.location.stadium.clone()).or_insert_with(|| StadiumAttendance9 {
+            stadium: environment.location.stadium.clone(),
+            ..Default::default()
+        });
+
+        let home_team = game.home_team().number();
+        if !stadium.home_teams.contains(&home_team) {
+            stadium.home_teams.push(home_team);
+        }
+        stadium.games += 1;
+        stadium.attendance += environment.attendance;
+        stadium.no_shows += environment.no_shows;
+        stadium.capacity += environment.capacity;
+        for (section, attendance) in &environment.sections {
+            stadium.sections.entry(*section).or_default().add(attendance);
+        }
+    }
+
+    pub fn to_text ( &self ) -> String {
+        let mut out = String::new();
+        for stadium in self.stadiums.values() {
+            let _ = writeln!(out, "{} (teams {:?}): {} games, {:.0} per game, {:.1}% sold, {} no shows", stadium.stadium, stadium.home_teams,
+                stadium.games, stadium.average_attendance(), stadium.sell_through(), stadium.no_shows);
+            for (section, attendance) in &stadium.sections {
+                let _ = writeln!(out, "  {:<12} {:>9} of {:>9} ({:.1}%)", section.to_string(), attendance.attendance(), attendance.capacity(), attendance.sell_through());
+            }
+        }
+        out
+    }
+
+    /// One row per stadium, sell through per section after the totals
+    pub fn to_csv ( &self ) -> String {
+        let mut out = String::from("stadium,games,attendance,no_shows,capacity,sell_through");
+        for section in SeatingSection9::ALL {
+            let _ = write!(out, ",{}", section.to_string().to_lowercase().replace(' ', "_"));
+        }
+        out.push('\n');
+
+        for stadium in self.stadiums.values() {
+            let _ = write!(out, "{},{},{},{},{},{:.1}", csv_field(&stadium.stadium), stadium.games, stadium.attendance,
+                stadium.no_shows, stadium.capacity, stadium.sell_through());
+            for section in SeatingSection9::ALL {
+                let _ = write!(out, ",{:.1}", stadium.sections.get(&section).map(|a| a.sell_through()).unwrap_or_default());
+            }
+            out.push('\n');
+        }
+        out
+    }
+}
+
+impl Display for AttendanceReport9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
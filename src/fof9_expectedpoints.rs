@@ -0,0 +1,231 @@
+use std::{collections::BTreeMap, fmt::{Display, Write}, fs, path::Path};
+
+use crate::{fof9_scoring::{play_score, tries, ScoreType9}, fof9_weekdata::{Game9Data, GamePlay9, GamePlayInfo9, Week9Data}};
+
+const TOUCHDOWN_VALUE: f64 = 7.0;  // with the usual try
+const MIN_CELL_PLAYS: u32 = 20;  // smaller cells fall back to coarser buckets
+const CSV_HEADER: &str = "down,distance,field,plays,points";
+
+
+/// (down, distance bucket, field bucket); 0 stands for "any" in the coarser fallback cells
+type Cell9 = (u32, u32, u32);
+
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub struct ExpectedPointsCell9 {
+    pub plays: u32,
+    pub points: f64,  // summed next score, from the offense's side
+}
+
+impl ExpectedPointsCell9 {
+    pub fn expected_points ( &self ) -> f64 {
+        if self.plays == 0 { 0.0 } else { self.points / f64::from(self.plays) }
+    }
+}
+
+/// Expected points by down, distance and field position, fit on a league's own plays
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct ExpectedPoints9 {
+    cells: BTreeMap<Cell9, ExpectedPointsCell9>,
+}
+
+impl ExpectedPoints9 {
+    /// Fit on every run and pass in the weeks other than two point tries; the value of a play is the next score in the same half
+    pub fn fit ( weeks: &[Week9Data] ) -> Self {
+        let mut model = ExpectedPoints9::default();
+        for game in weeks.iter().flat_map(|week| week.games.iter()) {
+            model.add_game(game);
+        }
+        model
+    }
+
+    pub fn add_game ( &mut self, game: &Game9Data ) {
+        let plays: Vec<_> = game.plays().collect();
+        for ((info, next_score), is_try) in plays.iter().zip(next_scores(&plays)).zip(tries(&plays)) {
+            if is_try || !matches!(info.play, GamePlay9::Run { .. } | GamePlay9::Pass { .. }) {
+                continue;
+            }
+            let value = match next_score {
+                Some((team, points)) if team == info.off_team => points,
+                Some((_, points)) => -points,
+                None => 0.0,
+            };
+            let (down, distance, field) = cell(info.down, info.yards_to_go, info.yards_to_goal());
+            for key in [(down, distance, field), (down, 0, field), (0, 0, field)] {
+                let cell = self.cells.entry(key).or_default();
+                cell.plays += 1;
+                cell.points += value;
+            }
+        }
+    }
+
+    /// From the most specific bucket with enough plays
+    pub fn expected_points ( &self, down: u32, yards_to_go: u32, yards_to_goal: u32 ) -> f64 {
+        let (down, distance, field) = cell(down, yards_to_go, yards_to_goal);
+        [(down, distance, field), (down, 0, field), (0, 0, field)].iter()
+            .filter_map(|key| self.cells.get(key))
+            .find(|cell| cell.plays >= MIN_CELL_PLAYS)
+            .map(|cell| cell.expected_points())
+            .unwrap_or_default()
+    }
+
+    pub fn play_expected_points ( &self, info: &GamePlayInfo9 ) -> f64 {
+        self.expected_points(info.down, info.yards_to_go, info.yards_to_goal())
+    }
+
+    pub fn cells ( &self ) -> impl Iterator<Item = (&Cell9, &ExpectedPointsCell9)> {
+        self.cells.iter()
+    }
+
+    pub fn to_csv ( &self ) -> String {
+        let mut out = String::from(CSV_HEADER);
+        out.push('\n');
+        for ((down, distance, field), cell) in &self.cells {
+            let _ = writeln!(out, "{},{},{},{},{}", down, distance, field, cell.plays, cell.points);
+        }
+        out
+    }
+
+    pub fn from_csv ( csv: &str ) -> Option<Self> {
+        let mut lines = csv.lines();
+        if lines.next()?.trim() != CSV_HEADER {
+            return None;
+        }
+
+        let mut cells = BTreeMap::new();
+        for line in lines.filter(|line| !line.trim().is_empty()) {
+            let fields: Vec<&str> = line.split(',').map(str::trim).collect();
+            if fields.len() != 5 {
+                return None;
+            }
+            let key: Cell9 = (fields[0].parse().ok()?, fields[1].parse().ok()?, fields[2].parse().ok()?);
+            // down and distance may be 0 for the fallback cells, the field bucket is always set
+            if key.0 > 4 || key.1 as usize > DISTANCE_LABELS.len() || !(1..=FIELD_LABELS.len()).contains(&(key.2 as usize)) {
+                return None;
+            }
+            cells.insert(key, ExpectedPointsCell9 { plays: fields[3].parse().ok()?, points: fields[4].parse().ok()? });
+        }
+        Some(ExpectedPoints9 { cells })
+    }
+
+    /// Saves the fitted model, so a league only has to be fit once
+    pub fn save<P: AsRef<Path>> ( &self, path: P ) -> std::io::Result<()> {
+        fs::write(path, self.to_csv())
+    }
+
+    pub fn load<P: AsRef<Path>> ( path: P ) -> Option<Self> {
+        Self::from_csv(&fs::read_to_string(path).ok()?)
+    }
+}
+
+impl Display for ExpectedPoints9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        writeln!(f, "{:<6}{:>6}{:>10}{:>8}{:>8}", "Down", "Dist", "To goal", "Plays", "EP")?;
+        let label = |labels: &[&'static str], bucket: u32| (bucket as usize).checked_sub(1).and_then(|index| labels.get(index).copied()).unwrap_or("?");
+        for ((down, distance, field), cell) in self.cells.iter().filter(|((down, distance, _), _)| *down != 0 && *distance != 0) {
+            writeln!(f, "{:<6}{:>6}{:>10}{:>8}{:>8.2}", down, label(&DISTANCE_LABELS, *distance), label(&FIELD_LABELS, *field), cell.plays, cell.expected_points())?;
+        }
+        Ok(())
+    }
+}
+
+const DISTANCE_LABELS: [&str; 4] = ["1-3", "4-6", "7-10", "11+"];
+const FIELD_LABELS: [&str; 10] = ["1-10", "11-20", "21-30", "31-40", "41-50", "51-60", "61-70", "71-80", "81-90", "91-99"];
+
+/// Buckets are numbered from 1
+fn cell ( down: u32, yards_to_go: u32, yards_to_goal: u32 ) -> Cell9 {
+    let distance = match yards_to_go {
+        0..=3 => 1,
+        4..=6 => 2,
+        7..=10 => 3,
+        _ => 4,
+    };
+    let field = (yards_to_goal.clamp(1, 99) - 1) / 10 + 1;
+    (down.clamp(1, 4), distance, field)
+}
+
+fn half ( quarter: u32 ) -> u32 {
+    match quarter {
+        0..=2 => 1,
+        3..=4 => 2,
+        overtime => overtime,
+    }
+}
+
+/// The team and value of the next score in the same half, for each play; tries after touchdowns are part of the touchdown
+fn next_scores ( plays: &[GamePlayInfo9] ) -> Vec<Option<(u32, f64)>> {
+    let mut next: Option<(u32, f64)> = None;
+    let mut current_half = 0;
+    let mut scores: Vec<Option<(u32, f64)>> = plays.iter().rev().map(|info| {
+        if half(info.quarter) != current_half {
+            current_half = half(info.quarter);
+            next = None;
+        }
+        if let Some(score) = score_value(info) {
+            next = Some(score);
+        }
+        next
+    }).collect();
+    scores.reverse();
+    scores
+}
+
+fn score_value ( info: &GamePlayInfo9 ) -> Option<(u32, f64)> {
+    match play_score(info)? {
+        (_, ScoreType9::ExtraPoint | ScoreType9::TwoPointConversion) => None,
+        (team, score_type) if score_type.is_touchdown() => Some((team, TOUCHDOWN_VALUE)),
+        (team, score_type) => Some((team, f64::from(score_type.points()))),
+    }
+}
+
+/// Expected points before and after a play, from the side of the team with the ball
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayValue9 {
+    pub play_index: usize,  // index into Game9Data::plays()
+    pub expected_before: f64,
+    pub expected_after: f64,
+}
+
+impl PlayValue9 {
+    /// Expected points added
+    pub fn epa ( &self ) -> f64 {
+        self.expected_after - self.expected_before
+    }
+}
+
+pub(crate) fn play_values ( game: &Game9Data, model: &ExpectedPoints9 ) -> Vec<PlayValue9> {
+    let plays: Vec<_> = game.plays().collect();
+    let tries = tries(&plays);
+    let is_down = |index: usize| !tries[index] &&
+        matches!(plays[index].play, GamePlay9::Run { .. } | GamePlay9::Pass { .. } | GamePlay9::FieldGoal { .. } | GamePlay9::Punt { .. });
+
+    plays.iter().enumerate().filter(|&(play_index, _)| is_down(play_index)).map(|(play_index, info)| {
+        let expected_after = match score_value(info) {
+            Some((team, points)) => if team == info.off_team { points } else { -points },
+            None => match (play_index + 1..plays.len()).find(|&next| is_down(next)).map(|next| &plays[next]) {
+                Some(next) if half(next.quarter) == half(info.quarter) => {
+                    let expected = model.play_expected_points(next);
+                    if next.off_team == info.off_team { expected } else { -expected }
+                },
+                _ => 0.0,
+            },
+        };
+        PlayValue9 { play_index, expected_before: model.play_expected_points(info), expected_after }
+    }).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{ExpectedPoints9, CSV_HEADER};
+
+    #[test]
+    fn csv_keys_in_range () {
+        let csv = |row: &str| format!("{}\n{}\n", CSV_HEADER, row);
+        let model = ExpectedPoints9::from_csv(&csv("1,2,10,25,50.5")).expect("valid row");
+        assert!(model.to_string().contains("91-99"));
+        assert_eq!(ExpectedPoints9::from_csv(&model.to_csv()).map(|model| model.cells().count()), Some(1));
+
+        for row in ["5,1,1,25,50", "1,5,1,25,50", "1,1,0,25,50", "1,1,11,25,50"] {
+            assert!(ExpectedPoints9::from_csv(&csv(row)).is_none(), "{} should be rejected", row);
+        }
+    }
+}
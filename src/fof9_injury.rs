@@ -0,0 +1,123 @@
+use std::fmt::{Display, Write};
+
+use crate::{fof9_playerdata::Players9Data, fof9_utility::csv_field, fof9_weekdata::{Game9Data, Week9Data}};
+
+
+/// An injury, with where in the season it happened
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct GameInjury9 {
+    pub year: u32,
+    pub week: u32,
+    pub home_team: u32,  // league team numbers
+    pub away_team: u32,
+    pub game_team: Option<u32>,  // 0 = home, 1 = away, when the player shows up in the play result
+    pub play_index: usize,  // index into Game9Data::plays()
+    pub quarter: u32,
+    pub minutes_remaining: u32,
+    pub seconds_remaining: u32,
+    pub play: String,  // as the play displays
+    pub player_id: u32,
+    pub player_name: Option<String>,
+    pub injury_code: u32,  // not decoded: the type, body part and severity are unknown
+}
+
+impl GameInjury9 {
+    /// League team number of the injured player's side
+    pub fn team ( &self ) -> Option<u32> {
+        self.game_team.map(|game_team| [self.home_team, self.away_team][game_team as usize])
+    }
+}
+
+impl Display for GameInjury9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} week {}, {} at {}, {}Q {}:{:02}: {} ({}), injury {}", self.year, self.week, self.away_team, self.home_team,
+            self.quarter, self.minutes_remaining, self.seconds_remaining,
+            self.player_name.clone().unwrap_or_else(|| format!("#{}", self.player_id)), self.player_id, self.injury_code)
+    }
+}
+
+/// Every injury in a set of weeks, in play order
+#[derive(Debug, Clone, Default)]
+pub struct InjuryReport9 {
+    pub injuries: Vec<GameInjury9>,
+}
+
+impl InjuryReport9 {
+    pub fn new ( weeks: &[Week9Data], players: Option<&Players9Data> ) -> Self {
+        let mut report = InjuryReport9::default();
+        for game in weeks.iter().flat_map(|week| week.games.iter()) {
+            report.injuries.extend(game.injuries(players));
+        }
+        report.injuries.sort_by_key(|injury| (injury.year, injury.week));
+        report
+    }
+
+    pub fn player_injuries ( &self, player_id: u32 ) -> impl Iterator<Item = &GameInjury9> {
+        self.injuries.iter().filter(move |injury| injury.player_id == player_id)
+    }
+
+    pub fn team_injuries ( &self, team: u32 ) -> impl Iterator<Item = &GameInjury9> {
+        self.injuries.iter().filter(move |injury| injury.team() == Some(team))
+    }
+
+    pub fn to_text ( &self ) -> String {
+        self.injuries.iter().map(|injury| injury.to_string() + "\n").collect()
+    }
+
+    /// One row per injury, with a header row
+    pub fn to_csv ( &self ) -> String {
+        let mut out = String::from("year,week,home_team,away_team,team,quarter,time,player_id,player,injury_code,play\n");
+        for injury in &self.injuries {
+            let _ = writeln!(out, "{},{},{},{},{},{},{}:{:02},{},{},{},{}",
+                injury.year, injury.week, injury.home_team, injury.away_team,
+                injury.team().map(|team| team.to_string()).unwrap_or_default(),
+                injury.quarter, injury.minutes_remaining, injury.seconds_remaining,
+                injury.player_id, csv_field(injury.player_name.as_deref().unwrap_or_default()),
+                injury.injury_code,
+                csv_field(&injury.play));
+        }
+        out
+    }
+}
+
+impl Display for InjuryReport9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
+
+pub(crate) fn injuries ( game: &Game9Data, players: Option<&Players9Data> ) -> Vec<GameInjury9> {
+    let (home_team, away_team) = (game.home_team().number(), game.away_team().number());
+
+    game.plays().enumerate().filter_map(|(play_index, info)| {
+        let injury = info.play.injury()?;
+        let player_id = injury.player_id()?;
+        let player = players.and_then(|p| p.player_data(player_id));
+
+        // from the play rather than the player's current team, which is wrong for players traded since;
+        // the player ids in the result are experimental (see PlayResult9), and players not in it stay unknown
+        let game_team = info.play.play_result().and_then(|result| {
+            if [result.ball_carrier(), result.passer(), result.target()].contains(&Some(player_id)) {
+                Some(info.off_team)
+            } else if [result.tackler(), result.assist_tackler(), result.intercepted_by()].contains(&Some(player_id)) {
+                Some(info.def_team())
+            } else { None }
+        });
+
+        Some(GameInjury9 {
+            year: game.year(),
+            week: game.week(),
+            home_team,
+            away_team,
+            game_team,
+            play_index,
+            quarter: info.quarter,
+            minutes_remaining: info.minutes_remaining,
+            seconds_remaining: info.seconds_remaining,
+            play: info.play.to_string(),
+            player_id,
+            player_name: player.map(|p| p.name()),
+            injury_code: injury.injury_code(),
+        })
+    }).collect()
+}
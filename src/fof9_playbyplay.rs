@@ -0,0 +1,220 @@
+use std::fmt::Display;
+
+use crate::{fof9_penalty::PenaltyType9, fof9_playerdata::Players9Data, fof9_playresult::{KickResult9, KickType9, PlayResult9, RunDirection9, ScrimmagePlay9}, fof9_weekdata::{Game9Data, GamePlay9, GamePlayInfo9}};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub enum PlayByPlayStyle9 {
+    /// One compact line per play, from the raw results
+    Terse,
+    /// Sentences like the in-game log
+    #[default]
+    Normal,
+    /// Normal, followed by the formations, spy and blitzers
+    Verbose,
+}
+
+/// Writes a game log, one line per play
+#[derive(Debug, Clone, Copy)]
+pub struct PlayByPlay9<'a> {
+    game: &'a Game9Data,
+    players: Option<&'a Players9Data>,
+    style: PlayByPlayStyle9,
+}
+
+impl<'a> PlayByPlay9<'a> {
+    pub fn new ( game: &'a Game9Data ) -> Self {
+        PlayByPlay9 { game, players: None, style: PlayByPlayStyle9::default() }
+    }
+
+    /// Player names instead of player ids
+    pub fn with_players ( mut self, players: &'a Players9Data ) -> Self {
+        self.players = Some(players);
+        self
+    }
+
+    pub fn style ( mut self, style: PlayByPlayStyle9 ) -> Self {
+        self.style = style;
+        self
+    }
+
+    pub fn lines ( &self ) -> Vec<String> {
+        self.game.plays().map(|info| self.line(&info)).collect()
+    }
+
+    pub fn line ( &self, info: &GamePlayInfo9 ) -> String {
+        match self.style {
+            PlayByPlayStyle9::Terse => self.terse_line(info),
+            PlayByPlayStyle9::Normal => self.normal_line(info),
+            PlayByPlayStyle9::Verbose => match info.play.formation_text() {
+                Some(formation) => format!("{} [{}]", self.normal_line(info), formation),
+                None => self.normal_line(info),
+            },
+        }
+    }
+
+    fn terse_line ( &self, info: &GamePlayInfo9 ) -> String {
+        format!("{}Q {:>2}:{:02} {}-{:<2} {:<5} {:<4} {}", info.quarter, info.minutes_remaining, info.seconds_remaining,
+            info.down, info.yards_to_go, self.game.field_yardline(info.yardline), self.game.team(info.off_team).short(), info.play)
+    }
+
+    fn normal_line ( &self, info: &GamePlayInfo9 ) -> String {
+        let clock = format!("{}:{:02} {}Q", info.minutes_remaining, info.seconds_remaining, info.quarter);
+
+        match info.play {
+            GamePlay9::Run { result, .. } | GamePlay9::Pass { result, .. } => {
+                let mut line = format!("{} & {} at {}, {}: {}", ordinal(info.down), info.yards_to_go, self.yardline(info.yardline), clock, self.describe_play(info, result));
+                if let Some(penalty) = info.play.penalty() {
+                    let flagged = match (penalty.is_offensive(), penalty.is_defensive()) {
+                        (true, true) => "both teams".to_string(),
+                        (true, false) => self.game.team(info.off_team).short(),
+                        _ => self.game.team(info.def_team()).short(),
+                    };
+                    let name = match penalty.penalty_type() {
+                        PenaltyType9::Unknown(_) => String::new(),
+                        penalty_type => format!(", {}", penalty_type),
+                    };
+                    line += &format!(" Penalty on {}{}, {} yards.", flagged, name, penalty.yards());
+                }
+                if let Some(injury) = info.play.injury() {
+                    line += &format!(" {} injured.", self.player(injury.player_id()));
+                }
+                line
+            },
+
+            GamePlay9::FieldGoal { result, .. } | GamePlay9::Kickoff { result, .. } |
+            GamePlay9::OnsideKick { result, .. } | GamePlay9::Punt { result, .. } => {
+                format!("{}: {}", clock, self.describe_kick(result))
+            },
+
+            GamePlay9::Special { .. } => match info.play.extra_point() {
+                Some(extra_point) => format!("{}: {} extra point: {}", clock, self.game.team(info.off_team).short(), extra_point),
+                None => format!("{}: {}", clock, info.play),
+            },
+        }
+    }
+
+    fn describe_play ( &self, info: &GamePlayInfo9, result: &PlayResult9 ) -> String {
+        let mut text = if result.sack() {
+            format!("{} sacked{} for {}", self.player(result.passer()), by(result.tackler().map(|id| self.player(Some(id)))), yards(result.yards_gained()))
+        } else if result.scramble() {
+            format!("{} scrambles for {}", self.player(result.passer()), yards(result.yards_gained()))
+        } else if result.play_type() == ScrimmagePlay9::Run {
+            let direction = match result.run_direction() {
+                Some(RunDirection9::None) | None => String::new(),
+                Some(direction) => format!(" {}", direction),
+            };
+            format!("{} rushes{} for {}", self.player(result.ball_carrier()), direction, yards(result.yards_gained()))
+        } else {
+            let depth = match result.pass_depth().map(|depth| depth.to_string()) {
+                Some(depth) if !depth.is_empty() => format!(" {}", depth),
+                _ => String::new(),
+            };
+            if result.interception() {
+                format!("{} pass{} intended for {} INTERCEPTED by {}", self.player(result.passer()), depth, self.player(result.target()), self.player(result.intercepted_by()))
+            } else if result.complete() {
+                format!("{} pass{} complete to {} for {}", self.player(result.passer()), depth, self.player(result.target()), yards(result.yards_gained()))
+            } else {
+                format!("{} pass{} incomplete intended for {}", self.player(result.passer()), depth, self.player(result.target()))
+            }
+        };
+
+        if !result.sack() && !result.interception() && result.tackler().is_some() && !result.touchdown() {
+            text += &format!(" (tackled by {}{})", self.player(result.tackler()),
+                result.assist_tackler().map(|id| format!(" and {}", self.player(Some(id)))).unwrap_or_default());
+        }
+        if result.fumble() {
+            text += &format!(", FUMBLE, recovered by {}", self.game.team(if result.fumble_lost() { info.def_team() } else { info.off_team }).short());
+        }
+        if result.touchdown() {
+            text += ", TOUCHDOWN";
+        } else if result.safety() {
+            text += ", SAFETY";
+        } else if result.first_down() {
+            text += ", first down";
+        }
+        text + "."
+    }
+
+    fn describe_kick ( &self, kick: &KickResult9 ) -> String {
+        let kicker = self.player(Some(kick.kicker()));
+        let mut text = match kick.kick_type() {
+            KickType9::FieldGoal => {
+                return format!("{} {} yard field goal is {}.", kicker, kick.distance(),
+                    kick.field_goal().map(|fg| fg.to_string()).unwrap_or_else(|| "no good".to_string()));
+            },
+            KickType9::Kickoff => format!("{} kicks off {} yards", kicker, kick.distance()),
+            KickType9::OnsideKick => format!("{} onside kick {} yards", kicker, kick.distance()),
+            KickType9::Punt => format!("{} punts {} yards", kicker, kick.distance()),
+        };
+
+        if kick.blocked() {
+            text += ", BLOCKED";
+        } else if kick.touchback() {
+            text += ", touchback";
+        } else if kick.fair_catch() {
+            text += &format!(", fair catch by {}", self.player(kick.returner()));
+        } else if kick.is_returned() {
+            text += &format!(", returned by {} for {}", self.player(kick.returner()), yards(kick.return_yards()));
+        }
+        if let Some(team) = kick.recovering_team() {
+            text += &format!(", recovered by {}", self.game.team(team).short());
+        }
+        if kick.touchdown() {
+            text += ", TOUCHDOWN";
+        }
+        text + "."
+    }
+
+    fn player ( &self, player_id: Option<u32> ) -> String {
+        match player_id {
+            Some(id) => match self.players.and_then(|players| players.player_data(id)) {
+                Some(player) => player.short_name(),
+                None => format!("#{}", id),
+            },
+            None => "unknown".to_string(),
+        }
+    }
+
+    /// "NYG 35", on the side of the field field_yardline shows
+    fn yardline ( &self, yardline: u32 ) -> String {
+        let (team, yards) = if yardline > 50 { (0, 100 - yardline.min(100)) } else { (1, yardline) };
+        if yards == 50 {
+            "50".to_string()
+        } else {
+            format!("{} {}", self.game.team(team).short(), yards)
+        }
+    }
+}
+
+impl Display for PlayByPlay9<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for line in self.lines() {
+            writeln!(f, "{}", line)?;
+        }
+        Ok(())
+    }
+}
+
+fn ordinal ( down: u32 ) -> String {
+    match down {
+        1 => "1st".to_string(),
+        2 => "2nd".to_string(),
+        3 => "3rd".to_string(),
+        _ => format!("{}th", down),
+    }
+}
+
+fn yards ( yards: i32 ) -> String {
+    match yards {
+        0 => "no gain".to_string(),
+        1 => "1 yard".to_string(),
+        -1 => "a loss of 1 yard".to_string(),
+        y if y < 0 => format!("a loss of {} yards", -y),
+        y => format!("{} yards", y),
+    }
+}
+
+fn by ( player: Option<String> ) -> String {
+    player.map(|name| format!(" by {}", name)).unwrap_or_default()
+}
@@ -0,0 +1,150 @@
+use std::fmt::Write;
+
+use crate::{fof9_playerdata::Player9Data, fof9_reference::ReferenceTables9};
+
+const NOT_DECODED: &str = "not yet decoded";
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CardFormat9 {
+    Text,
+    Markdown,
+}
+
+/// A report of everything known about a single player
+pub struct PlayerCard9<'a> {
+    player: &'a Player9Data,
+    tables: Option<&'a ReferenceTables9>,
+    current_year: Option<u32>,
+}
+
+// a titled list of label/value lines, or None where the data is not understood
+struct CardSection {
+    title: &'static str,
+    lines: Option<Vec<(String, String)>>,
+}
+
+impl<'a> PlayerCard9<'a> {
+    pub fn new ( player: &'a Player9Data ) -> Self {
+        PlayerCard9 { player, tables: None, current_year: None }
+    }
+
+    /// Needed to name the hometown and college
+    pub fn with_tables ( mut self, tables: &'a ReferenceTables9 ) -> Self {
+        self.tables = Some(tables);
+        self
+    }
+
+    /// Needed to show the player's age
+    pub fn with_year ( mut self, current_year: u32 ) -> Self {
+        self.current_year = Some(current_year);
+        self
+    }
+
+    pub fn to_text ( &self ) -> String {
+        self.render(CardFormat9::Text)
+    }
+
+    pub fn to_markdown ( &self ) -> String {
+        self.render(CardFormat9::Markdown)
+    }
+
+    pub fn render ( &self, format: CardFormat9 ) -> String {
+        let mut out = String::new();
+        let title = format!("{} {}, {}", self.player.position(), self.player.name(), self.player.player_id());
+
+        match format {
+            CardFormat9::Text => {
+                let _ = writeln!(out, "{}\n{}", title, "=".repeat(title.chars().count()));
+            },
+            CardFormat9::Markdown => {
+                let _ = writeln!(out, "# {}", title);
+            },
+        }
+
+        for section in self.sections() {
+            match format {
+                CardFormat9::Text => {
+                    let _ = writeln!(out, "\n{}", section.title);
+                    match section.lines {
+                        Some(lines) if lines.is_empty() => { let _ = writeln!(out, "  (none)"); },
+                        Some(lines) => {
+                            let width = lines.iter().map(|(label, _)| label.chars().count()).max().unwrap_or(0);
+                            for (label, value) in lines {
+                                let _ = writeln!(out, "  {:width$}  {}", label, value, width = width);
+                            }
+                        },
+                        None => { let _ = writeln!(out, "  ({})", NOT_DECODED); },
+                    }
+                },
+
+                CardFormat9::Markdown => {
+                    let _ = writeln!(out, "\n## {}\n", section.title);
+                    match section.lines {
+                        Some(lines) if lines.is_empty() => { let _ = writeln!(out, "_none_"); },
+                        Some(lines) => {
+                            let _ = writeln!(out, "| | |\n|---|---|");
+                            for (label, value) in lines {
+                                let _ = writeln!(out, "| {} | {} |", label, value.replace('|', "\\|"));
+                            }
+                        },
+                        None => { let _ = writeln!(out, "_{}_", NOT_DECODED); },
+                    }
+                },
+            }
+        }
+        out
+    }
+
+    fn sections ( &self ) -> Vec<CardSection> {
+        let player = self.player;
+        let unknown = |kind: &str, id: u32| format!("<{} {}>", kind, id);
+        let mut colleges = player.college_ids().to_vec();
+        colleges.dedup();
+
+        let mut bio = vec![
+            ("Position".to_string(), format!("{} ({})", player.position(), player.position_group())),
+            ("Experience".to_string(), format!("{} years", player.years_experience())),
+            ("Born".to_string(), player.birth().to_string()),
+            ("Hometown".to_string(), self.tables.and_then(|tables| tables.city(player.hometown_id())).map_or_else(|| unknown("hometown", player.hometown_id()), |city| city.to_string())),
+            ("College".to_string(), colleges.iter().map(|&id| self.tables.and_then(|tables| tables.college(id)).map_or_else(|| unknown("college", id), |college| college.to_string()))
+                .collect::<Vec<String>>().join(" / ")),
+            ("Team".to_string(), match player.team_id() {
+                Some(team) => format!("team {}, #{}", team, player.jersey_number().unwrap_or_default()),
+                None => "Free Agent".to_string(),
+            }),
+        ];
+        if let Some(nickname) = player.nickname() {
+            bio.insert(0, ("Nickname".to_string(), nickname.to_string()));
+        }
+        if let Some(year) = self.current_year {
+            bio.push(("Age".to_string(), player.age(year).to_string()));
+        }
+
+        let measurables = vec![
+            ("Height".to_string(), player.height().to_string().trim().to_string()),
+            ("Weight".to_string(), format!("{} lbs", player.weight())),
+            ("Hand".to_string(), player.hand().to_string().trim().to_string()),
+            ("Arm".to_string(), player.arm().to_string().trim().to_string()),
+        ];
+
+        let draft = vec![match player.draft() {
+            Some(draft) => ("Drafted".to_string(), draft.to_string()),
+            None => ("Drafted".to_string(), format!("Undrafted ({})", player.draft_year())),
+        }];
+
+        let timeline: Vec<(String, String)> = player.timeline().iter()
+            .map(|entry| (entry.year.to_string(), entry.to_string().split_once(": ").map_or(String::new(), |(_, rest)| rest.to_string())))
+            .collect();
+
+        vec![
+            CardSection { title: "Bio", lines: Some(bio) },
+            CardSection { title: "Measurables", lines: Some(measurables) },
+            CardSection { title: "Draft", lines: Some(draft) },
+            CardSection { title: "Contract", lines: None },
+            CardSection { title: "Ratings", lines: None },
+            CardSection { title: "Career Stats", lines: None },
+            CardSection { title: "History", lines: Some(timeline) },
+        ]
+    }
+}
@@ -1,7 +1,9 @@
-use std::fmt::Display;
+use std::{collections::BTreeMap, fmt::Display};
 use binrw::{binread, BinRead};
+use num_derive::FromPrimitive;
+use num_traits::FromPrimitive;
 
-use crate::fof9_utility::{Date, FixedString, LengthInches};
+use crate::{fof9_playerquery::PlayerQuery9, fof9_reference::ReferenceTables9, fof9_utility::{Date, FixedString, LengthInches}};
 
 #[binread]
 #[derive(Debug)]
@@ -48,7 +50,7 @@ pub struct AltPlayer9Data {
     // #[br(temp)]
     some1_count: u32,
     #[br(temp, count = some1_count)]
-    _some1: Vec<SomeData2>,
+    _some1: Vec<PlayerHistory9>,
 
     // #[br(temp)]
     some2_count: u32,
@@ -73,12 +75,12 @@ pub struct AltPlayer9Data {
     // #[br(temp)]
     past_count: u32,
     #[br(temp, count = past_count)]
-    _something_1: Vec<SomeData>,
+    _something_1: Vec<PlayerTeamYear9>,
 
     // #[br(temp)]
     current_count: u32,
     #[br(temp, count = current_count)]
-    _something_2: Vec<SomeData>,
+    _something_2: Vec<PlayerTeamYear9>,
 
     #[br(count = 3)]
     _what: Vec<u32>,
@@ -120,6 +122,9 @@ pub struct Players9Data {
     #[br(count = player_count)]
     players: Vec<Player9Data>,
 
+    #[br(calc = players.iter().enumerate().map(|(index, player)| (player.player_id, index)).collect())]
+    player_index: BTreeMap<u32, usize>,
+
     #[br(temp)]
     next_1_count: u32,
     #[br(count = next_1_count)]
@@ -157,7 +162,7 @@ impl Players9Data {
     }
 
     pub fn player_data ( &self, player_id: u32 ) -> Option<&Player9Data> {
-        self.players.iter().find(|&p| p.player_id() == player_id)
+        self.player_index.get(&player_id).map(|&index| &self.players[index])
     }
 
     // pub fn max_player_id ( &self ) -> u32 {
@@ -167,22 +172,261 @@ impl Players9Data {
     pub fn staff ( &self ) -> &Vec<StaffData9> {
         &self.staff
     }
+
+    pub fn query ( &self ) -> PlayerQuery9<'_> {
+        PlayerQuery9::new(self)
+    }
+
+    /// The current draft class, best ranked first
+    pub fn draft_prospects ( &self ) -> Vec<DraftProspect9<'_>> {
+        let mut prospects: Vec<DraftProspect9<'_>> = self.next_1.iter().map(|prospect| DraftProspect9 {
+            prospect,
+            player: self.player_data(prospect.player_id()),
+        }).collect();
+        prospects.sort_by_key(|p| p.prospect.board_rank());
+        prospects
+    }
+
+    /// All four scouting lists, the first being the current draft class
+    pub fn prospect_lists ( &self ) -> [&Vec<NextData9>; 4] {
+        [&self.next_1, &self.next_2, &self.next_3, &self.next_4]
+    }
+
+    pub fn pending_transactions ( &self ) -> Vec<PendingTransaction9<'_>> {
+        self.more_1.iter().map(|transaction| PendingTransaction9 {
+            transaction,
+            player: self.player_data(transaction.player_id()),
+        }).collect()
+    }
+
+    /// Players without a team
+    pub fn free_agents ( &self ) -> impl Iterator<Item = &Player9Data> {
+        self.players.iter().filter(|p| p.team_id().is_none())
+    }
+
+    pub fn players_by_college ( &self, tables: &ReferenceTables9 ) -> BTreeMap<String, Vec<&Player9Data>> {
+        let mut colleges = BTreeMap::<String, Vec<&Player9Data>>::new();
+        for player in &self.players {
+            let college = match tables.college(player.college_id()) {
+                Some(name) => name.to_string(),
+                None => format!("<college {}>", player.college_id()),
+            };
+            colleges.entry(college).or_default().push(player);
+        }
+        colleges
+    }
+
+    pub fn players_by_state ( &self, tables: &ReferenceTables9 ) -> BTreeMap<String, Vec<&Player9Data>> {
+        let mut states = BTreeMap::<String, Vec<&Player9Data>>::new();
+        for player in &self.players {
+            let state = match tables.city(player.hometown_id()) {
+                Some(city) => city.state.clone(),
+                None => format!("<hometown {}>", player.hometown_id()),
+            };
+            states.entry(state).or_default().push(player);
+        }
+        states
+    }
 }
 
 #[binread]
 #[derive(Debug)]
 pub struct NextData9 {
-    #[br(count = 147)]
+    player_id: u32,
+    board_rank: u32,  // ?
+    position_rank: u32,  // ?
+    grade_current: u32,  // as scouted, ?
+    grade_future: u32,  // as scouted, ?
+
+    dash: u32,  // hundredths of a second, ?
+    solo: u32,  // bench press reps, ?
+    agility: u32,  // hundredths of a second, ?
+    broad_jump: u32,  // inches, ?
+    position_drill: u32,  // ?
+
+    #[br(count = 137)]
     stuff: Vec<u32>
 }
 
+impl NextData9 {
+    pub fn player_id ( &self ) -> u32 {
+        self.player_id
+    }
+
+    pub fn board_rank ( &self ) -> u32 {
+        self.board_rank
+    }
+
+    pub fn position_rank ( &self ) -> u32 {
+        self.position_rank
+    }
+
+    pub fn grade_current ( &self ) -> u32 {
+        self.grade_current
+    }
+
+    pub fn grade_future ( &self ) -> u32 {
+        self.grade_future
+    }
+
+    pub fn combine ( &self ) -> CombineResults9 {
+        CombineResults9 {
+            dash: self.dash,
+            solo: self.solo,
+            agility: self.agility,
+            broad_jump: self.broad_jump,
+            position_drill: self.position_drill,
+        }
+    }
+}
+
+/// Workout numbers as the layout guesses them; the load_draft_pools test checks the dash is in a sane range
+#[derive(Debug, Clone, Copy)]
+pub struct CombineResults9 {
+    pub dash: u32,  // hundredths of a second, ?
+    pub solo: u32,  // bench press reps, ?
+    pub agility: u32,  // hundredths of a second, ?
+    pub broad_jump: u32,  // inches, ?
+    pub position_drill: u32,  // ?
+}
+
+impl Display for CombineResults9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "dash {}.{:02}, solo {}, agility {}.{:02}, jump {}'{}\", drill {}",
+            self.dash / 100, self.dash % 100,
+            self.solo,
+            self.agility / 100, self.agility % 100,
+            self.broad_jump / 12, self.broad_jump % 12,
+            self.position_drill,
+        )
+    }
+}
+
+/// A draft-eligible player, with their scouting entry
+#[derive(Debug, Clone, Copy)]
+pub struct DraftProspect9<'a> {
+    pub prospect: &'a NextData9,
+    pub player: Option<&'a Player9Data>,
+}
+
+impl DraftProspect9<'_> {
+    pub fn name ( &self ) -> String {
+        match self.player {
+            Some(player) => player.name(),
+            None => format!("<player {}>", self.prospect.player_id()),
+        }
+    }
+}
+
+impl Display for DraftProspect9<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}. {}{}, grade {}/{}, {}",
+            self.prospect.board_rank,
+            if let Some(player) = self.player { format!("{} ", player.position()) } else { String::new() },
+            self.name(),
+            self.prospect.grade_current, self.prospect.grade_future,
+            self.prospect.combine(),
+        )
+    }
+}
+
 #[binread]
 #[derive(Debug)]
 pub struct MoreData9 {
-    #[br(count = 53)]
+    player_id: u32,
+    team: u32,  // 99 = no team, ?
+    transaction: u32,  // see TransactionType9
+    year: u32,  // ?
+    stage: u32,  // ?
+    contract_years: u32,  // ?
+    contract_salary: u32,  // thousands?
+
+    #[br(count = 46)]
     stuff: Vec<u32>
 }
 
+impl MoreData9 {
+    pub fn player_id ( &self ) -> u32 {
+        self.player_id
+    }
+
+    pub fn team_id ( &self ) -> Option<usize> {
+        if self.team != 99 {
+            Some(self.team as usize)
+        } else { None }
+    }
+
+    pub fn transaction_type ( &self ) -> Option<TransactionType9> {
+        TransactionType9::from_u32(self.transaction)
+    }
+
+    pub fn year ( &self ) -> u32 {
+        self.year
+    }
+
+    pub fn stage ( &self ) -> u32 {
+        self.stage
+    }
+
+    pub fn contract_years ( &self ) -> u32 {
+        self.contract_years
+    }
+
+    pub fn contract_salary ( &self ) -> u32 {
+        self.contract_salary
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
+pub enum TransactionType9 {
+    FreeAgentOffer = 1,  // ?
+    FreeAgentSigning = 2,  // ?
+    ReSigning = 3,  // ?
+    Release = 4,  // ?
+    Trade = 5,  // ?
+    Franchise = 6,  // ?
+}
+
+impl Display for TransactionType9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            TransactionType9::FreeAgentOffer => "Free Agent Offer",
+            TransactionType9::FreeAgentSigning => "Free Agent Signing",
+            TransactionType9::ReSigning => "Re-signing",
+            TransactionType9::Release => "Release",
+            TransactionType9::Trade => "Trade",
+            TransactionType9::Franchise => "Franchise Tag",
+        })
+    }
+}
+
+/// A queued transaction, with the player involved
+#[derive(Debug, Clone, Copy)]
+pub struct PendingTransaction9<'a> {
+    pub transaction: &'a MoreData9,
+    pub player: Option<&'a Player9Data>,
+}
+
+impl Display for PendingTransaction9<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {}: {}{}{}",
+            self.transaction.year,
+            match self.transaction.transaction_type() {
+                Some(transaction_type) => transaction_type.to_string(),
+                None => format!("<transaction {}>", self.transaction.transaction),
+            },
+            match self.player {
+                Some(player) => player.name(),
+                None => format!("<player {}>", self.transaction.player_id),
+            },
+            if let Some(team) = self.transaction.team_id() { format!(", team {}", team) } else { String::new() },
+            if self.transaction.contract_years > 0 {
+                format!(", {} years at {}", self.transaction.contract_years, self.transaction.contract_salary)
+            } else { String::new() },
+        )
+    }
+}
+
 #[binread]
 #[derive(Debug)]
 pub struct StaffData9 {
@@ -192,7 +436,7 @@ pub struct StaffData9 {
     lastname: FixedString,
 
     #[br(count = 41)]
-    stuff_1: Vec<u32>,
+    stuff_1: Vec<u32>,  // role, team, ratings and contract are in here somewhere
 
     #[br(temp)]
     list_count: u32,
@@ -221,6 +465,13 @@ impl StaffData9 {
     pub fn name ( &self ) -> String {
         format!("{} {}", self.firstname, self.lastname)
     }
+
+    /// Past and present jobs, oldest first, as far as the guessed row layout goes
+    pub fn career ( &self ) -> Vec<StaffCareerEntry9> {
+        let mut career: Vec<StaffCareerEntry9> = self.list.iter().map(|item| item.career_entry()).collect();
+        career.sort_by_key(|entry| entry.year);
+        career
+    }
 }
 
 impl Display for StaffData9 {
@@ -231,6 +482,29 @@ impl Display for StaffData9 {
     }
 }
 
+/// One row of a staff member's job list; the meaning of each of the 7 values is a guess
+#[derive(Debug, Clone, Copy)]
+pub struct StaffCareerEntry9 {
+    pub year: u32,  // ?
+    pub team: Option<u32>,  // ?
+    pub role: u32,  // not decoded
+    pub wins: u32,  // ?
+    pub losses: u32,  // ?
+    pub ties: u32,  // ?
+    pub playoff_result: u32,  // ?
+}
+
+impl Display for StaffCareerEntry9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}: {}{}, {}-{}-{}",
+            self.year,
+            format_args!("<role {}>", self.role),
+            if let Some(team) = self.team { format!(", team {}", team) } else { String::new() },
+            self.wins, self.losses, self.ties,
+        )
+    }
+}
+
 #[binread]
 #[derive(Debug)]
 pub struct StaffSmall1 {
@@ -238,11 +512,29 @@ pub struct StaffSmall1 {
     stuff: Vec<u32>,
 }
 
-#[binread]
-#[derive(Debug)]
+#[derive(BinRead, Debug)]
 pub struct StaffListItem9 {
-    #[br(count = 7)]
-    stuff: Vec<u32>,
+    year: u32,  // ?
+    team: u32,  // 99 = no team?
+    role: u32,  // ?
+    wins: u32,  // ?
+    losses: u32,  // ?
+    ties: u32,  // ?
+    playoff_result: u32,  // ?
+}
+
+impl StaffListItem9 {
+    fn career_entry ( &self ) -> StaffCareerEntry9 {
+        StaffCareerEntry9 {
+            year: self.year,
+            team: if self.team != 99 { Some(self.team) } else { None },
+            role: self.role,
+            wins: self.wins,
+            losses: self.losses,
+            ties: self.ties,
+            playoff_result: self.playoff_result,
+        }
+    }
 }
 
 #[binread]
@@ -276,7 +568,7 @@ pub struct Player9Data {
     birth: Date,  // 3 u32
 
     #[br(count = 2)]
-    home: Vec<u32>,  // not translated
+    home: Vec<u32>,  // city id, ?
 
     college_1: u32,  // enrolled?
     college_2: u32,  // graduated?
@@ -306,7 +598,7 @@ pub struct Player9Data {
     #[br(temp)]
     some1_count: u32,
     #[br(count = some1_count)]
-    some1: Vec<SomeData2>,
+    history: Vec<PlayerHistory9>,
 
     #[br(temp)]
     some2_count: u32,
@@ -331,12 +623,12 @@ pub struct Player9Data {
     #[br(temp)]
     past_count: u32,
     #[br(count = past_count)]
-    something_1: Vec<SomeData>,
+    past_teams: Vec<PlayerTeamYear9>,
 
     #[br(temp)]
     current_count: u32,
     #[br(count = current_count)]
-    something_2: Vec<SomeData>,
+    current_teams: Vec<PlayerTeamYear9>,
 
     what_1: u32,
     what_2: u32,
@@ -372,11 +664,135 @@ impl Player9Data {
         format!("{} {}", self.firstname, self.lastname)
     }
 
+    /// First initial and last name, as game logs show it
+    pub fn short_name ( &self ) -> String {
+        match self.firstname.to_string().chars().next() {
+            Some(initial) => format!("{}. {}", initial, self.lastname),
+            None => self.lastname.to_string(),
+        }
+    }
+
     pub fn team_id ( &self ) -> Option<usize> {
         if self.current_team != 99 {
             Some(self.current_team as usize)
         } else { None }
     }
+
+    pub fn hometown_id ( &self ) -> u32 {
+        self.home[0]
+    }
+
+    /// The first of the two stored college ids
+    pub fn college_id ( &self ) -> u32 {
+        self.college_1
+    }
+
+    /// Both stored college ids; which is the enrolled one and which the graduated one is not confirmed
+    pub fn college_ids ( &self ) -> [u32; 2] {
+        [self.college_1, self.college_2]
+    }
+
+    pub fn nickname ( &self ) -> Option<&str> {
+        if self.nickname.string.is_empty() { None } else { Some(&self.nickname.string) }
+    }
+
+    pub fn years_experience ( &self ) -> u32 {
+        self.years_experience
+    }
+
+    pub fn height ( &self ) -> LengthInches {
+        self.height
+    }
+
+    pub fn hand ( &self ) -> LengthInches {
+        self.hand
+    }
+
+    pub fn arm ( &self ) -> LengthInches {
+        self.arm
+    }
+
+    pub fn weight ( &self ) -> u32 {
+        self.weight
+    }
+
+    /// None if undrafted
+    pub fn draft ( &self ) -> Option<DraftInfo9> {
+        if self.draft_team != 99 {
+            Some(DraftInfo9 {
+                year: self.first_draft_year,
+                round: self.draft_round,
+                pick: self.draft_pick,
+                overall: self.draft_overall,
+                team: self.draft_team,
+            })
+        } else { None }
+    }
+
+    pub fn birth ( &self ) -> Date {
+        self.birth
+    }
+
+    pub fn age ( &self, current_year: u32 ) -> u32 {
+        self.birth.age(current_year)
+    }
+
+    pub fn draft_year ( &self ) -> u32 {
+        self.first_draft_year
+    }
+
+    pub fn jersey_number ( &self ) -> Option<u32> {
+        if self.current_team != 99 {
+            Some(self.jersey_number)
+        } else { None }
+    }
+
+    /// Transactions, injuries and team stints, in year order (stints first within a year)
+    pub fn timeline ( &self ) -> Vec<PlayerTimelineEntry9> {
+        let mut timeline: Vec<PlayerTimelineEntry9> = self.past_teams.iter().chain(self.current_teams.iter())
+            .map(|stint| PlayerTimelineEntry9 {
+                year: stint.year,
+                team: if stint.team != 99 { Some(stint.team) } else { None },
+                item: PlayerTimelineItem9::TeamStint,
+            })
+            .chain(self.history.iter().map(|history| PlayerTimelineEntry9 {
+                year: history.year,
+                team: if history.team != 99 { Some(history.team) } else { None },
+                item: PlayerTimelineItem9::Event {
+                    event: PlayerEvent9::from_u32(history.event),
+                    raw_event: history.event,
+                    detail: history.detail,
+                },
+            }))
+            .collect();
+        timeline.sort_by_key(|entry| (entry.year, !matches!(entry.item, PlayerTimelineItem9::TeamStint)));
+        timeline
+    }
+
+    /// The teams played for in each year
+    pub fn teams_by_year ( &self ) -> BTreeMap<u32, Vec<u32>> {
+        let mut teams = BTreeMap::<u32, Vec<u32>>::new();
+        for stint in self.past_teams.iter().chain(self.current_teams.iter()).filter(|stint| stint.team != 99) {
+            let year_teams = teams.entry(stint.year).or_default();
+            if !year_teams.contains(&stint.team) {
+                year_teams.push(stint.team);
+            }
+        }
+        teams
+    }
+
+    /// The jersey number as stored, whether or not the player has a team
+    pub(crate) fn stored_jersey_number ( &self ) -> u32 {
+        self.jersey_number
+    }
+
+    pub fn current_ratings ( &self ) -> &RelativeStats9 {
+        &self.overall_1
+    }
+
+    pub fn future_ratings ( &self ) -> &RelativeStats9 {
+        &self.overall_2
+    }
 }
 
 impl Display for Player9Data {
@@ -422,18 +838,87 @@ impl Display for Player9Data {
 //     stuff: Vec<u32>,
 // }
 
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct DraftInfo9 {
+    pub year: u32,
+    pub round: u32,
+    pub pick: u32,  // in round
+    pub overall: u32,
+    pub team: u32,
+}
+
+impl Display for DraftInfo9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} round {}, pick {} ({} overall) by team {}", self.year, self.round, self.pick, self.overall, self.team)
+    }
+}
+
 #[derive(BinRead, Debug)]
-pub struct SomeData {
+pub struct PlayerTeamYear9 {
     year: u32,
-    something: u32,
+    team: u32,  // 99 = no team
 }
 
 #[derive(BinRead, Debug)]
-pub struct SomeData2 {
-    year: u32,
-    some_1: u32,
-    some_2: u32,
-    some_3: u32,
+pub struct PlayerHistory9 {
+    year: u32,  // ?
+    event: u32,  // see PlayerEvent9, ?
+    team: u32,  // 99 = no team, ?
+    detail: u32,  // injury, or contract years, ?
+}
+
+// codes are guesses; load_team_players checks that a drafted player's first event is the draft, by the drafting team
+#[derive(Debug, Clone, Copy, PartialEq, Eq, FromPrimitive)]
+pub enum PlayerEvent9 {
+    Drafted = 1,  // ?
+    Signed = 2,  // ?
+    ReSigned = 3,  // ?
+    Traded = 4,  // ?
+    Released = 5,  // ?
+    Injured = 6,  // ?
+    Retired = 7,  // ?
+}
+
+impl Display for PlayerEvent9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            PlayerEvent9::Drafted => "Drafted",
+            PlayerEvent9::Signed => "Signed",
+            PlayerEvent9::ReSigned => "Re-signed",
+            PlayerEvent9::Traded => "Traded",
+            PlayerEvent9::Released => "Released",
+            PlayerEvent9::Injured => "Injured",
+            PlayerEvent9::Retired => "Retired",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerTimelineItem9 {
+    Event {
+        event: Option<PlayerEvent9>,  // None if not understood
+        raw_event: u32,
+        detail: u32,
+    },
+    TeamStint,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayerTimelineEntry9 {
+    pub year: u32,
+    pub team: Option<u32>,
+    pub item: PlayerTimelineItem9,
+}
+
+impl Display for PlayerTimelineEntry9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let team = if let Some(team) = self.team { format!("team {}", team) } else { "no team".to_string() };
+        match self.item {
+            PlayerTimelineItem9::Event { event: Some(event), .. } => write!(f, "{}: {}, {}", self.year, event, team),
+            PlayerTimelineItem9::Event { event: None, raw_event, detail } => write!(f, "{}: <event {}/{}>, {}", self.year, raw_event, detail, team),
+            PlayerTimelineItem9::TeamStint => write!(f, "{}: with {}", self.year, team),
+        }
+    }
 }
 
 #[derive(BinRead, Debug)]
@@ -452,10 +937,20 @@ pub struct SomeData4 {
 #[derive(BinRead, Debug)]
 pub struct RelativeStats9 {
     #[br(count = 64)]
-    stats: Vec<u32>,
+    stats: Vec<u32>,  // order not decoded
 }
 
-#[derive(BinRead, Clone, Copy, Debug)]
+impl RelativeStats9 {
+    pub fn rating ( &self, index: usize ) -> Option<u32> {
+        self.stats.get(index).copied()
+    }
+
+    pub fn ratings ( &self ) -> &[u32] {
+        &self.stats
+    }
+}
+
+#[derive(BinRead, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PlayerPosition9 {
     #[br(magic = 1u32)] QB,
     #[br(magic = 2u32)] RB,
@@ -523,7 +1018,7 @@ impl Display for PlayerPosition9 {
     }
 }
 
-#[derive(BinRead, Clone, Copy, Debug)]
+#[derive(BinRead, Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
 pub enum PlayerPositionGroup9 {
     #[br(magic = 1u32)] QB,
     #[br(magic = 2u32)] RB,
@@ -569,3 +1064,31 @@ impl Display for PlayerPositionGroup9 {
     }
 }
 
+#[cfg(test)]
+mod tests {
+    use crate::fof9_testdata::TestBytes;
+
+    use super::StaffData9;
+
+    #[test]
+    fn staff_record_layout () {
+        let staff: StaffData9 = TestBytes::new()
+            .u32(1234)
+            .string("Vince").string("Lombardi")
+            .u32s(0..41)
+            .u32(2)
+            .u32s([2021, 5, 1, 10, 7, 0, 0, 2020, 5, 1, 9, 8, 0, 0])
+            .u32(1)
+            .zeros(8 + 36)
+            .read();
+        assert_eq!(staff.staff_id(), 1234);
+        assert_eq!(staff.name(), "Vince Lombardi");
+        assert_eq!(staff.stuff_1.len(), 41);
+        assert_eq!(staff.stuff_1[40], 40);
+
+        let career = staff.career();
+        assert_eq!(career.iter().map(|entry| entry.year).collect::<Vec<_>>(), [2020, 2021]);
+        assert_eq!(career[0].team, Some(5));
+        assert_eq!((career[1].wins, career[1].losses), (10, 7));
+    }
+}
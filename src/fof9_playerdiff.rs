@@ -0,0 +1,212 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::fof9_playerdata::{Player9Data, Players9Data, PlayerPosition9};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerChange9 {
+    /// In the later snapshot only
+    New,
+    /// In the earlier snapshot only: retired, released from the file or deleted
+    Removed,
+    Team { from: Option<usize>, to: Option<usize> },
+    Position { from: PlayerPosition9, to: PlayerPosition9 },
+    Jersey { from: Option<u32>, to: Option<u32> },
+    /// The rating order is not decoded (see RelativeStats9), so all rating moves are reported together:
+    /// how many entries changed and the net change of the current and future values
+    Ratings { changed: usize, current: i64, future: i64 },
+}
+
+impl Display for PlayerChange9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let team = |team: &Option<usize>| match team {
+            Some(team) => format!("team {}", team),
+            None => "free agency".to_string(),
+        };
+        let jersey = |jersey: &Option<u32>| match jersey {
+            Some(jersey) => format!("#{}", jersey),
+            None => "none".to_string(),
+        };
+
+        match self {
+            PlayerChange9::New => write!(f, "new player"),
+            PlayerChange9::Removed => write!(f, "removed"),
+            PlayerChange9::Team { from, to } => write!(f, "moved from {} to {}", team(from), team(to)),
+            PlayerChange9::Position { from, to } => write!(f, "position {} to {}", from, to),
+            PlayerChange9::Jersey { from, to } => write!(f, "jersey {} to {}", jersey(from), jersey(to)),
+            PlayerChange9::Ratings { changed, current, future } => write!(f, "{} ratings {:+}/{:+}", changed, current, future),
+        }
+    }
+}
+
+/// All the changes to a single player
+#[derive(Debug, Clone)]
+pub struct PlayerDiff9<'a> {
+    pub player_id: u32,
+    pub before: Option<&'a Player9Data>,
+    pub after: Option<&'a Player9Data>,
+    pub changes: Vec<PlayerChange9>,
+}
+
+impl PlayerDiff9<'_> {
+    pub fn player ( &self ) -> &Player9Data {
+        self.after.or(self.before).expect("diff has no player")
+    }
+}
+
+impl Display for PlayerDiff9<'_> {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let player = self.player();
+        write!(f, "{} {} ({}): {}", player.position(), player.name(), self.player_id,
+            self.changes.iter().map(|c| c.to_string()).collect::<Vec<String>>().join(", "),
+        )
+    }
+}
+
+/// Differences between two snapshots of the same league's players, by player id
+#[derive(Debug, Clone)]
+pub struct PlayersDiff9<'a> {
+    diffs: BTreeMap<u32, PlayerDiff9<'a>>,
+}
+
+impl<'a> PlayersDiff9<'a> {
+    pub fn new ( before: &'a Players9Data, after: &'a Players9Data ) -> Self {
+        Self::from_players(before.players(), after.players())
+    }
+
+    pub fn from_players ( before: &'a [Player9Data], after: &'a [Player9Data] ) -> Self {
+        let mut diffs = BTreeMap::<u32, PlayerDiff9>::new();
+        let before_ids: BTreeMap<u32, &Player9Data> = before.iter().map(|p| (p.player_id(), p)).collect();
+        let after_ids: BTreeMap<u32, &Player9Data> = after.iter().map(|p| (p.player_id(), p)).collect();
+
+        for old in before {
+            let new = after_ids.get(&old.player_id()).copied();
+            let changes = match new {
+                Some(new) => compare_players(old, new),
+                None => vec![PlayerChange9::Removed],
+            };
+            if !changes.is_empty() {
+                diffs.insert(old.player_id(), PlayerDiff9 { player_id: old.player_id(), before: Some(old), after: new, changes });
+            }
+        }
+
+        for new in after.iter().filter(|p| !before_ids.contains_key(&p.player_id())) {
+            diffs.insert(new.player_id(), PlayerDiff9 { player_id: new.player_id(), before: None, after: Some(new), changes: vec![PlayerChange9::New] });
+        }
+
+        PlayersDiff9 { diffs }
+    }
+
+    pub fn diffs ( &self ) -> impl Iterator<Item = &PlayerDiff9<'a>> {
+        self.diffs.values()
+    }
+
+    pub fn player_diff ( &self, player_id: u32 ) -> Option<&PlayerDiff9<'a>> {
+        self.diffs.get(&player_id)
+    }
+
+    pub fn is_empty ( &self ) -> bool {
+        self.diffs.is_empty()
+    }
+
+    pub fn new_players ( &self ) -> impl Iterator<Item = &PlayerDiff9<'a>> {
+        self.with_change(|c| matches!(c, PlayerChange9::New))
+    }
+
+    pub fn removed_players ( &self ) -> impl Iterator<Item = &PlayerDiff9<'a>> {
+        self.with_change(|c| matches!(c, PlayerChange9::Removed))
+    }
+
+    pub fn team_changes ( &self ) -> impl Iterator<Item = &PlayerDiff9<'a>> {
+        self.with_change(|c| matches!(c, PlayerChange9::Team { .. }))
+    }
+
+    pub fn position_changes ( &self ) -> impl Iterator<Item = &PlayerDiff9<'a>> {
+        self.with_change(|c| matches!(c, PlayerChange9::Position { .. }))
+    }
+
+    pub fn jersey_changes ( &self ) -> impl Iterator<Item = &PlayerDiff9<'a>> {
+        self.with_change(|c| matches!(c, PlayerChange9::Jersey { .. }))
+    }
+
+    pub fn rating_changes ( &self ) -> impl Iterator<Item = &PlayerDiff9<'a>> {
+        self.with_change(|c| matches!(c, PlayerChange9::Ratings { .. }))
+    }
+
+    fn with_change<F: Fn(&PlayerChange9) -> bool> ( &self, check: F ) -> impl Iterator<Item = &PlayerDiff9<'a>> {
+        self.diffs.values().filter(move |diff| diff.changes.iter().any(&check))
+    }
+
+    /// One line per changed player, transactions first
+    pub fn changelog ( &self ) -> String {
+        let mut lines: Vec<(u8, String)> = self.diffs.values().map(|diff| {
+            let order = match diff.changes.first() {
+                Some(PlayerChange9::New | PlayerChange9::Removed) => 0,
+                Some(PlayerChange9::Team { .. }) => 1,
+                Some(PlayerChange9::Position { .. } | PlayerChange9::Jersey { .. }) => 2,
+                _ => 3,
+            };
+            (order, diff.to_string())
+        }).collect();
+        lines.sort_by_key(|(order, _)| *order);
+        lines.into_iter().map(|(_, line)| line + "\n").collect()
+    }
+}
+
+fn compare_players ( old: &Player9Data, new: &Player9Data ) -> Vec<PlayerChange9> {
+    let mut changes = Vec::new();
+
+    if old.team_id() != new.team_id() {
+        changes.push(PlayerChange9::Team { from: old.team_id(), to: new.team_id() });
+    }
+    if old.position() != new.position() {
+        changes.push(PlayerChange9::Position { from: old.position(), to: new.position() });
+    }
+    if old.jersey_number() != new.jersey_number() {
+        changes.push(PlayerChange9::Jersey { from: old.jersey_number(), to: new.jersey_number() });
+    }
+
+    let delta = |old: Option<u32>, new: Option<u32>| i64::from(new.unwrap_or_default()) - i64::from(old.unwrap_or_default());
+    let (mut changed, mut current, mut future) = (0, 0, 0);
+    for index in 0..old.current_ratings().ratings().len().max(new.current_ratings().ratings().len()) {
+        let current_delta = delta(old.current_ratings().rating(index), new.current_ratings().rating(index));
+        let future_delta = delta(old.future_ratings().rating(index), new.future_ratings().rating(index));
+        if current_delta != 0 || future_delta != 0 {
+            changed += 1;
+            current += current_delta;
+            future += future_delta;
+        }
+    }
+    if changed > 0 {
+        changes.push(PlayerChange9::Ratings { changed, current, future });
+    }
+
+    changes
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fof9_playerdata::{Player9Data, PlayerPosition9}, fof9_testdata::player_record, PlayerChange9, PlayersDiff9};
+
+    fn player ( player_id: u32, position: u32, team: u32, jersey: u32, rating: u32 ) -> Player9Data {
+        player_record(player_id, position, team, jersey, rating).read()
+    }
+
+    #[test]
+    fn diff_snapshots () {
+        let before = vec![player(1, 1, 4, 12, 50), player(2, 2, 4, 28, 40), player(3, 3, 7, 44, 30)];
+        let after = vec![player(1, 1, 9, 12, 55), player(3, 3, 7, 44, 30), player(4, 4, 99, 0, 20)];
+        let diff = PlayersDiff9::from_players(&before, &after);
+
+        assert!(diff.player_diff(3).is_none());
+        assert_eq!(diff.removed_players().map(|d| d.player_id).collect::<Vec<_>>(), [2]);
+        assert_eq!(diff.new_players().map(|d| d.player_id).collect::<Vec<_>>(), [4]);
+
+        let moved = diff.player_diff(1).expect("player 1 changed");
+        assert_eq!(moved.changes, [
+            PlayerChange9::Team { from: Some(4), to: Some(9) },
+            PlayerChange9::Ratings { changed: 64, current: 64 * 5, future: 64 * 5 },
+        ]);
+        assert_eq!(moved.player().position(), PlayerPosition9::QB);
+    }
+}
@@ -0,0 +1,190 @@
+use std::{cmp::{Ordering, Reverse}, ops::{Bound, RangeBounds}};
+
+use crate::fof9_playerdata::{Player9Data, Players9Data, PlayerPosition9, PlayerPositionGroup9};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PlayerSort9 {
+    Id,
+    Name,
+    Position,
+    Team,
+    Experience,
+    Age,
+    DraftYear,
+    /// By one entry of the current ratings
+    Rating(usize),
+    FutureRating(usize),
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum TeamFilter {
+    Team(usize),
+    FreeAgent,
+}
+
+type U32Range = (Bound<u32>, Bound<u32>);
+type PlayerPredicate<'a> = Box<dyn Fn(&Player9Data) -> bool + 'a>;
+
+/// Filters and sorts the players in a `Players9Data`
+///
+/// Filters of the same kind (positions, groups, teams) are alternatives; different kinds must all match.
+pub struct PlayerQuery9<'a> {
+    players: &'a Players9Data,
+    positions: Vec<PlayerPosition9>,
+    groups: Vec<PlayerPositionGroup9>,
+    teams: Vec<TeamFilter>,
+    experience: Option<U32Range>,
+    age: Option<(U32Range, u32)>,  // range, current year
+    draft_year: Option<U32Range>,
+    ratings: Vec<(usize, U32Range)>,
+    future_ratings: Vec<(usize, U32Range)>,
+    predicates: Vec<PlayerPredicate<'a>>,
+    sort: Option<(PlayerSort9, bool)>,  // sort, descending
+    limit: Option<usize>,
+}
+
+impl<'a> PlayerQuery9<'a> {
+    pub fn new ( players: &'a Players9Data ) -> Self {
+        PlayerQuery9 {
+            players,
+            positions: Vec::new(),
+            groups: Vec::new(),
+            teams: Vec::new(),
+            experience: None,
+            age: None,
+            draft_year: None,
+            ratings: Vec::new(),
+            future_ratings: Vec::new(),
+            predicates: Vec::new(),
+            sort: None,
+            limit: None,
+        }
+    }
+
+    pub fn position ( mut self, position: PlayerPosition9 ) -> Self {
+        self.positions.push(position);
+        self
+    }
+
+    pub fn position_group ( mut self, group: PlayerPositionGroup9 ) -> Self {
+        self.groups.push(group);
+        self
+    }
+
+    pub fn team ( mut self, team: usize ) -> Self {
+        self.teams.push(TeamFilter::Team(team));
+        self
+    }
+
+    /// Players without a team (stored as team 99)
+    pub fn free_agents ( mut self ) -> Self {
+        self.teams.push(TeamFilter::FreeAgent);
+        self
+    }
+
+    pub fn experience<R: RangeBounds<u32>> ( mut self, years: R ) -> Self {
+        self.experience = Some(to_bounds(years));
+        self
+    }
+
+    /// Age is taken as that reached during `current_year`
+    pub fn age<R: RangeBounds<u32>> ( mut self, ages: R, current_year: u32 ) -> Self {
+        self.age = Some((to_bounds(ages), current_year));
+        self
+    }
+
+    pub fn draft_year<R: RangeBounds<u32>> ( mut self, years: R ) -> Self {
+        self.draft_year = Some(to_bounds(years));
+        self
+    }
+
+    /// Filter on a single entry of the current ratings
+    pub fn rating<R: RangeBounds<u32>> ( mut self, index: usize, ratings: R ) -> Self {
+        self.ratings.push((index, to_bounds(ratings)));
+        self
+    }
+
+    pub fn future_rating<R: RangeBounds<u32>> ( mut self, index: usize, ratings: R ) -> Self {
+        self.future_ratings.push((index, to_bounds(ratings)));
+        self
+    }
+
+    pub fn filter<F: Fn(&Player9Data) -> bool + 'a> ( mut self, predicate: F ) -> Self {
+        self.predicates.push(Box::new(predicate));
+        self
+    }
+
+    pub fn sort_by ( mut self, sort: PlayerSort9 ) -> Self {
+        self.sort = Some((sort, false));
+        self
+    }
+
+    pub fn sort_by_descending ( mut self, sort: PlayerSort9 ) -> Self {
+        self.sort = Some((sort, true));
+        self
+    }
+
+    pub fn limit ( mut self, limit: usize ) -> Self {
+        self.limit = Some(limit);
+        self
+    }
+
+    pub fn matches ( &self, player: &Player9Data ) -> bool {
+        (self.positions.is_empty() || self.positions.contains(&player.position()))
+            && (self.groups.is_empty() || self.groups.contains(&player.position_group()))
+            && (self.teams.is_empty() || self.teams.iter().any(|team| match team {
+                TeamFilter::Team(team) => player.team_id() == Some(*team),
+                TeamFilter::FreeAgent => player.team_id().is_none(),
+            }))
+            && self.experience.is_none_or(|range| range.contains(&player.years_experience()))
+            && self.age.is_none_or(|(range, year)| range.contains(&player.age(year)))
+            && self.draft_year.is_none_or(|range| range.contains(&player.draft_year()))
+            && self.ratings.iter().all(|(index, range)| {
+                player.current_ratings().rating(*index).is_some_and(|rating| range.contains(&rating))
+            })
+            && self.future_ratings.iter().all(|(index, range)| {
+                player.future_ratings().rating(*index).is_some_and(|rating| range.contains(&rating))
+            })
+            && self.predicates.iter().all(|predicate| predicate(player))
+    }
+
+    pub fn results ( &self ) -> Vec<&'a Player9Data> {
+        let mut results: Vec<&Player9Data> = self.players.players().iter().filter(|p| self.matches(p)).collect();
+
+        // ties keep their file order either way
+        if let Some((sort, descending)) = self.sort {
+            results.sort_by(|a, b| if descending { compare(sort, b, a) } else { compare(sort, a, b) });
+        }
+
+        if let Some(limit) = self.limit {
+            results.truncate(limit);
+        }
+        results
+    }
+
+    pub fn count ( &self ) -> usize {
+        self.players.players().iter().filter(|p| self.matches(p)).count()
+    }
+}
+
+fn compare ( sort: PlayerSort9, a: &Player9Data, b: &Player9Data ) -> Ordering {
+    match sort {
+        PlayerSort9::Id => a.player_id().cmp(&b.player_id()),
+        PlayerSort9::Name => a.name().cmp(&b.name()),
+        PlayerSort9::Position => a.position().cmp(&b.position()),
+        PlayerSort9::Team => a.team_id().unwrap_or(usize::MAX).cmp(&b.team_id().unwrap_or(usize::MAX)),
+        PlayerSort9::Experience => a.years_experience().cmp(&b.years_experience()),
+        PlayerSort9::Age => {
+            let birth = |p: &Player9Data| { let b = p.birth(); Reverse((b.year(), b.month(), b.day())) };
+            birth(a).cmp(&birth(b))
+        },
+        PlayerSort9::DraftYear => a.draft_year().cmp(&b.draft_year()),
+        PlayerSort9::Rating(index) => a.current_ratings().rating(index).cmp(&b.current_ratings().rating(index)),
+        PlayerSort9::FutureRating(index) => a.future_ratings().rating(index).cmp(&b.future_ratings().rating(index)),
+    }
+}
+
+fn to_bounds<R: RangeBounds<u32>> ( range: R ) -> U32Range {
+    (range.start_bound().cloned(), range.end_bound().cloned())
+}
@@ -0,0 +1,580 @@
+use std::fmt::Display;
+
+// layout of the kicking play data, indexes into the 421 values
+// none of these has been checked against a known game yet; the kick_results test in load_week checks the kicker
+const KICK_KICKER: usize = 0;  // player id, ?
+const KICK_DISTANCE: usize = 1;  // yards, ?
+const KICK_HANG_TIME: usize = 2;  // tenths of a second, ?
+const KICK_RETURNER: usize = 3;  // player id, 0 = none, ?
+const KICK_RETURN_YARDS: usize = 4;  // signed, ?
+const KICK_TOUCHBACK: usize = 5;  // ?
+const KICK_FAIR_CATCH: usize = 6;  // ?
+const KICK_BLOCKED: usize = 7;  // ?
+const KICK_GOOD: usize = 8;  // field goals, ?
+const KICK_MISS: usize = 9;  // field goals, same codes as extra points, ?
+const KICK_RECOVERING_TEAM: usize = 10;  // stored as 0 = none, 1 = home, 2 = away, ?
+const KICK_TOUCHDOWN: usize = 11;  // returned for a touchdown, ?
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum KickType9 {
+    FieldGoal,
+    Kickoff,
+    OnsideKick,
+    Punt,
+}
+
+impl Display for KickType9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            KickType9::FieldGoal => "Field Goal",
+            KickType9::Kickoff => "Kickoff",
+            KickType9::OnsideKick => "Onside Kick",
+            KickType9::Punt => "Punt",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum FieldGoalResult9 {
+    Good,
+    WideLeft,
+    LeftUpright,
+    WideRight,
+    RightUpright,
+    Blocked,
+    Short,  // ?
+    Unknown(u32),
+}
+
+impl FieldGoalResult9 {
+    fn from_codes ( good: u32, miss: u32 ) -> Self {
+        match (good, miss) {
+            (1, _) => FieldGoalResult9::Good,
+            (_, 1) => FieldGoalResult9::WideLeft,
+            (_, 2) => FieldGoalResult9::LeftUpright,
+            (_, 3) => FieldGoalResult9::WideRight,
+            (_, 4) => FieldGoalResult9::RightUpright,
+            (_, 5) => FieldGoalResult9::Blocked,
+            (_, 6) => FieldGoalResult9::Short,
+            (_, miss) => FieldGoalResult9::Unknown(miss),
+        }
+    }
+
+    pub fn is_good ( &self ) -> bool {
+        matches!(self, FieldGoalResult9::Good)
+    }
+}
+
+impl Display for FieldGoalResult9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            FieldGoalResult9::Good => write!(f, "Good"),
+            FieldGoalResult9::WideLeft => write!(f, "Wide Left"),
+            FieldGoalResult9::LeftUpright => write!(f, "Hit Left Upright"),
+            FieldGoalResult9::WideRight => write!(f, "Wide Right"),
+            FieldGoalResult9::RightUpright => write!(f, "Hit Right Upright"),
+            FieldGoalResult9::Blocked => write!(f, "Blocked"),
+            FieldGoalResult9::Short => write!(f, "Short"),
+            FieldGoalResult9::Unknown(code) => write!(f, "<miss {}>", code),
+        }
+    }
+}
+
+/// The outcome of a field goal, kickoff, onside kick or punt
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct KickResult9 {
+    kick_type: KickType9,
+    kicker: u32,
+    distance: u32,
+    hang_time: u32,  // tenths of a second
+    returner: Option<u32>,
+    return_yards: i32,
+    touchback: bool,
+    fair_catch: bool,
+    blocked: bool,
+    field_goal: Option<FieldGoalResult9>,  // field goals only
+    recovering_team: Option<u32>,  // game team (0 = home, 1 = away); onside kicks and fumbles
+    touchdown: bool,
+}
+
+impl KickResult9 {
+    pub(crate) fn from_data ( kick_type: KickType9, data: &[u32] ) -> Self {
+        let value = |index: usize| data.get(index).copied().unwrap_or_default();
+        let flag = |index: usize| value(index) == 1;
+
+        KickResult9 {
+            kick_type,
+            kicker: value(KICK_KICKER),
+            distance: value(KICK_DISTANCE),
+            hang_time: value(KICK_HANG_TIME),
+            returner: match value(KICK_RETURNER) { 0 => None, id => Some(id) },
+            return_yards: value(KICK_RETURN_YARDS) as i32,
+            touchback: flag(KICK_TOUCHBACK),
+            fair_catch: flag(KICK_FAIR_CATCH),
+            blocked: flag(KICK_BLOCKED),
+            field_goal: if kick_type == KickType9::FieldGoal {
+                Some(if flag(KICK_BLOCKED) { FieldGoalResult9::Blocked } else { FieldGoalResult9::from_codes(value(KICK_GOOD), value(KICK_MISS)) })
+            } else { None },
+            recovering_team: match value(KICK_RECOVERING_TEAM) {
+                1 => Some(0),
+                2 => Some(1),
+                _ => None,
+            },
+            touchdown: flag(KICK_TOUCHDOWN),
+        }
+    }
+
+    /// From the GamePlay9 variant, so not a guess
+    pub fn kick_type ( &self ) -> KickType9 {
+        self.kick_type
+    }
+}
+
+/// Experimental: read from the KICK_* offsets, none of which has been checked against a known game.
+/// The raw values are in the play's `data` and show in GamePlay9's Display.
+impl KickResult9 {
+    pub fn kicker ( &self ) -> u32 {
+        self.kicker
+    }
+
+    pub fn distance ( &self ) -> u32 {
+        self.distance
+    }
+
+    /// Tenths of a second
+    pub fn hang_time ( &self ) -> u32 {
+        self.hang_time
+    }
+
+    pub fn returner ( &self ) -> Option<u32> {
+        self.returner
+    }
+
+    pub fn return_yards ( &self ) -> i32 {
+        self.return_yards
+    }
+
+    pub fn touchback ( &self ) -> bool {
+        self.touchback
+    }
+
+    pub fn fair_catch ( &self ) -> bool {
+        self.fair_catch
+    }
+
+    pub fn blocked ( &self ) -> bool {
+        self.blocked
+    }
+
+    /// Field goals only
+    pub fn field_goal ( &self ) -> Option<FieldGoalResult9> {
+        self.field_goal
+    }
+
+    /// Game team (0 = home, 1 = away); onside kicks and fumbles
+    pub fn recovering_team ( &self ) -> Option<u32> {
+        self.recovering_team
+    }
+
+    pub fn touchdown ( &self ) -> bool {
+        self.touchdown
+    }
+
+    pub fn is_returned ( &self ) -> bool {
+        self.returner.is_some() && !self.touchback && !self.fair_catch
+    }
+
+    /// A kickoff or punt the receiving team lost; onside kicks and blocked kicks are not counted
+    pub fn is_turnover ( &self, kicking_team: u32 ) -> bool {
+        matches!(self.kick_type, KickType9::Kickoff | KickType9::Punt) && !self.blocked && self.recovering_team == Some(kicking_team)
+    }
+}
+
+impl Display for KickResult9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{} {} yds", self.kick_type, self.distance)?;
+        if let Some(result) = self.field_goal {
+            return write!(f, ", {}", result);
+        }
+        if self.blocked {
+            write!(f, ", blocked")?;
+        } else if self.touchback {
+            write!(f, ", touchback")?;
+        } else if self.fair_catch {
+            write!(f, ", fair catch")?;
+        } else if self.is_returned() {
+            write!(f, ", returned {} yds", self.return_yards)?;
+        }
+        match self.recovering_team {
+            Some(0) => write!(f, ", recovered by home")?,
+            Some(_) => write!(f, ", recovered by away")?,
+            None => {},
+        }
+        if self.touchdown {
+            write!(f, ", touchdown")?;
+        }
+        Ok(())
+    }
+}
+
+// layout of the run and pass play data, indexes into the 373 values
+// the first three are from the notes on GamePlay9::Run; the rest have not been checked against a known game
+// (the play_results test in load_week checks the clock and the players); the raw values still show in GamePlay9's Display
+const PLAY_MINUTES_AFTER: usize = 0;  // minutes left
+const PLAY_SECONDS_AFTER: usize = 1;  // seconds left
+const PLAY_PENALTY_PLAYER: usize = 2;  // flagged player, a guess from the free slot between the clock and the ball carrier, ?
+const PLAY_BALL_CARRIER: usize = 3;  // player id, runner or receiver, ?
+const PLAY_PASSER: usize = 4;  // ?
+const PLAY_TARGET: usize = 5;  // ?
+const PLAY_YARDS_GAINED: usize = 6;  // signed, ?
+const PLAY_AIR_YARDS: usize = 7;  // signed, ?
+const PLAY_COMPLETE: usize = 8;  // ?
+const PLAY_SACK: usize = 9;  // ?
+const PLAY_SCRAMBLE: usize = 10;  // ?
+const PLAY_INTERCEPTION: usize = 11;  // ?
+const PLAY_INTERCEPTED_BY: usize = 12;  // ?
+const PLAY_FUMBLE: usize = 13;  // ?
+const PLAY_FUMBLE_LOST: usize = 14;  // ?
+pub(crate) const PLAY_TOUCHDOWN: usize = 15;  // ?
+const PLAY_FIRST_DOWN: usize = 16;  // ?
+const PLAY_TACKLER: usize = 17;  // ?
+const PLAY_ASSIST_TACKLER: usize = 18;  // ?
+const PLAY_SAFETY: usize = 19;  // ?
+const PLAY_RUN_DIRECTION: usize = 20;  // 0 = none, 1 = left, 2 = middle, 3 = right, ?
+const PLAY_PASS_DEPTH: usize = 21;  // 0 = screen, 1 = short, 2 = medium, 3 = long, ?
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScrimmagePlay9 {
+    Run,
+    Pass,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum RunDirection9 {
+    Left,
+    Middle,
+    Right,
+    None,
+}
+
+impl Display for RunDirection9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            RunDirection9::Left => "left",
+            RunDirection9::Middle => "up the middle",
+            RunDirection9::Right => "right",
+            RunDirection9::None => "",
+        })
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PassDepth9 {
+    Screen,
+    Short,
+    Medium,
+    Long,
+    Other,
+}
+
+impl Display for PassDepth9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            PassDepth9::Screen => "screen",
+            PassDepth9::Short => "short",
+            PassDepth9::Medium => "medium",
+            PassDepth9::Long => "long",
+            PassDepth9::Other => "",
+        })
+    }
+}
+
+/// The outcome of a run or pass play; player fields are player ids
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct PlayResult9 {
+    play_type: ScrimmagePlay9,
+    minutes_after: u32,
+    seconds_after: u32,
+    penalty_player: Option<u32>,
+    ball_carrier: Option<u32>,
+    passer: Option<u32>,
+    target: Option<u32>,
+    yards_gained: i32,
+    air_yards: Option<i32>,  // pass attempts only
+    complete: bool,
+    sack: bool,
+    scramble: bool,
+    interception: bool,
+    intercepted_by: Option<u32>,
+    fumble: bool,
+    fumble_lost: bool,
+    touchdown: bool,
+    first_down: bool,
+    safety: bool,
+    tackler: Option<u32>,
+    assist_tackler: Option<u32>,
+    run_direction: Option<RunDirection9>,  // runs only
+    pass_depth: Option<PassDepth9>,  // pass attempts only
+}
+
+impl PlayResult9 {
+    pub(crate) fn from_data ( play_type: ScrimmagePlay9, data: &[u32] ) -> Self {
+        let value = |index: usize| data.get(index).copied().unwrap_or_default();
+        let flag = |index: usize| value(index) == 1;
+        let player = |index: usize| match value(index) { 0 => None, id => Some(id) };
+
+        let sack = play_type == ScrimmagePlay9::Pass && flag(PLAY_SACK);
+        let scramble = play_type == ScrimmagePlay9::Pass && flag(PLAY_SCRAMBLE);
+        let attempt = play_type == ScrimmagePlay9::Pass && !sack && !scramble;
+
+        PlayResult9 {
+            play_type,
+            minutes_after: value(PLAY_MINUTES_AFTER),
+            seconds_after: value(PLAY_SECONDS_AFTER),
+            penalty_player: player(PLAY_PENALTY_PLAYER),
+            ball_carrier: player(PLAY_BALL_CARRIER),
+            passer: if play_type == ScrimmagePlay9::Pass { player(PLAY_PASSER) } else { None },
+            target: if attempt { player(PLAY_TARGET) } else { None },
+            yards_gained: value(PLAY_YARDS_GAINED) as i32,
+            air_yards: if attempt { Some(value(PLAY_AIR_YARDS) as i32) } else { None },
+            complete: attempt && flag(PLAY_COMPLETE),
+            sack,
+            scramble,
+            interception: attempt && flag(PLAY_INTERCEPTION),
+            intercepted_by: if attempt && flag(PLAY_INTERCEPTION) { player(PLAY_INTERCEPTED_BY) } else { None },
+            fumble: flag(PLAY_FUMBLE),
+            fumble_lost: flag(PLAY_FUMBLE_LOST),
+            touchdown: flag(PLAY_TOUCHDOWN),
+            first_down: flag(PLAY_FIRST_DOWN),
+            safety: flag(PLAY_SAFETY),
+            tackler: player(PLAY_TACKLER),
+            assist_tackler: player(PLAY_ASSIST_TACKLER),
+            run_direction: if play_type == ScrimmagePlay9::Run {
+                Some(match value(PLAY_RUN_DIRECTION) {
+                    1 => RunDirection9::Left,
+                    2 => RunDirection9::Middle,
+                    3 => RunDirection9::Right,
+                    _ => RunDirection9::None,
+                })
+            } else { None },
+            pass_depth: if attempt {
+                Some(match value(PLAY_PASS_DEPTH) {
+                    0 => PassDepth9::Screen,
+                    1 => PassDepth9::Short,
+                    2 => PassDepth9::Medium,
+                    3 => PassDepth9::Long,
+                    _ => PassDepth9::Other,
+                })
+            } else { None },
+        }
+    }
+
+    /// From the GamePlay9 variant, so not a guess
+    pub fn play_type ( &self ) -> ScrimmagePlay9 {
+        self.play_type
+    }
+
+    /// Game clock after the play, from the notes on GamePlay9::Run
+    pub fn minutes_after ( &self ) -> u32 {
+        self.minutes_after
+    }
+
+    pub fn seconds_after ( &self ) -> u32 {
+        self.seconds_after
+    }
+}
+
+/// Experimental: read from the PLAY_* offsets past the clock, none of which has been checked against a known game.
+/// The raw values are in the play's `data` and show in GamePlay9's Display.
+impl PlayResult9 {
+    /// The flagged player, the least certain of the offsets
+    pub fn penalty_player ( &self ) -> Option<u32> {
+        self.penalty_player
+    }
+
+    /// Runner or receiver
+    pub fn ball_carrier ( &self ) -> Option<u32> {
+        self.ball_carrier
+    }
+
+    pub fn passer ( &self ) -> Option<u32> {
+        self.passer
+    }
+
+    pub fn target ( &self ) -> Option<u32> {
+        self.target
+    }
+
+    pub fn yards_gained ( &self ) -> i32 {
+        self.yards_gained
+    }
+
+    /// Pass attempts only
+    pub fn air_yards ( &self ) -> Option<i32> {
+        self.air_yards
+    }
+
+    pub fn complete ( &self ) -> bool {
+        self.complete
+    }
+
+    pub fn sack ( &self ) -> bool {
+        self.sack
+    }
+
+    pub fn scramble ( &self ) -> bool {
+        self.scramble
+    }
+
+    pub fn interception ( &self ) -> bool {
+        self.interception
+    }
+
+    pub fn intercepted_by ( &self ) -> Option<u32> {
+        self.intercepted_by
+    }
+
+    pub fn fumble ( &self ) -> bool {
+        self.fumble
+    }
+
+    pub fn fumble_lost ( &self ) -> bool {
+        self.fumble_lost
+    }
+
+    pub fn touchdown ( &self ) -> bool {
+        self.touchdown
+    }
+
+    pub fn first_down ( &self ) -> bool {
+        self.first_down
+    }
+
+    pub fn safety ( &self ) -> bool {
+        self.safety
+    }
+
+    pub fn tackler ( &self ) -> Option<u32> {
+        self.tackler
+    }
+
+    pub fn assist_tackler ( &self ) -> Option<u32> {
+        self.assist_tackler
+    }
+
+    /// Runs only
+    pub fn run_direction ( &self ) -> Option<RunDirection9> {
+        self.run_direction
+    }
+
+    /// Pass attempts only
+    pub fn pass_depth ( &self ) -> Option<PassDepth9> {
+        self.pass_depth
+    }
+
+    /// A pass thrown, so not a sack or scramble
+    pub fn is_pass_attempt ( &self ) -> bool {
+        self.play_type == ScrimmagePlay9::Pass && !self.sack && !self.scramble
+    }
+
+    /// A designed run or a scramble
+    pub fn is_rush ( &self ) -> bool {
+        self.play_type == ScrimmagePlay9::Run || self.scramble
+    }
+
+    pub fn is_turnover ( &self ) -> bool {
+        self.interception || self.fumble_lost
+    }
+}
+
+impl Display for PlayResult9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        if self.sack {
+            write!(f, "sacked for {} yds", self.yards_gained)?;
+        } else if self.scramble {
+            write!(f, "scramble for {} yds", self.yards_gained)?;
+        } else if self.play_type == ScrimmagePlay9::Run {
+            write!(f, "run {} for {} yds", self.run_direction.unwrap_or(RunDirection9::None), self.yards_gained)?;
+        } else if self.interception {
+            write!(f, "pass intercepted")?;
+        } else if self.complete {
+            write!(f, "pass complete for {} yds", self.yards_gained)?;
+        } else {
+            write!(f, "pass incomplete")?;
+        }
+        if self.fumble {
+            write!(f, ", fumble{}", if self.fumble_lost { " lost" } else { "" })?;
+        }
+        if self.touchdown {
+            write!(f, ", touchdown")?;
+        } else if self.safety {
+            write!(f, ", safety")?;
+        } else if self.first_down {
+            write!(f, ", first down")?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn kick ( kick_type: KickType9 ) -> KickResult9 {
+        KickResult9 {
+            kick_type, kicker: 812, distance: 45, hang_time: 0, returner: Some(300), return_yards: 12,
+            touchback: false, fair_catch: false, blocked: false, field_goal: None, recovering_team: None, touchdown: false,
+        }
+    }
+
+    // the offsets themselves are unverified, so only the logic built on the decoded values is tested
+    #[test]
+    fn kick_logic () {
+        assert_eq!(FieldGoalResult9::from_codes(1, 0), FieldGoalResult9::Good);
+        assert_eq!(FieldGoalResult9::from_codes(0, 3), FieldGoalResult9::WideRight);
+        assert_eq!(FieldGoalResult9::from_codes(0, 9), FieldGoalResult9::Unknown(9));
+        assert!(FieldGoalResult9::from_codes(1, 0).is_good() && !FieldGoalResult9::from_codes(0, 5).is_good());
+
+        let punt = kick(KickType9::Punt);
+        assert!(punt.is_returned() && !punt.is_turnover(0));
+        assert!(!KickResult9 { fair_catch: true, ..punt }.is_returned());
+        assert!(!KickResult9 { touchback: true, ..punt }.is_returned());
+
+        // a muffed punt recovered by the kicking team is a turnover, unless the kick was blocked
+        let muffed = KickResult9 { recovering_team: Some(0), ..punt };
+        assert!(muffed.is_turnover(0) && !muffed.is_turnover(1));
+        assert!(!KickResult9 { blocked: true, ..muffed }.is_turnover(0));
+        assert!(!KickResult9 { kick_type: KickType9::OnsideKick, ..muffed }.is_turnover(0));
+    }
+
+    fn play ( play_type: ScrimmagePlay9 ) -> PlayResult9 {
+        PlayResult9 {
+            play_type, minutes_after: 12, seconds_after: 41, penalty_player: None,
+            ball_carrier: Some(300), passer: Some(200), target: Some(300), yards_gained: 14, air_yards: Some(-2),
+            complete: true, sack: false, scramble: false, interception: false, intercepted_by: None,
+            fumble: false, fumble_lost: false, touchdown: false, first_down: true, safety: false,
+            tackler: None, assist_tackler: None, run_direction: None, pass_depth: Some(PassDepth9::Screen),
+        }
+    }
+
+    #[test]
+    fn play_logic () {
+        let pass = play(ScrimmagePlay9::Pass);
+        assert!(pass.is_pass_attempt() && !pass.is_rush() && !pass.is_turnover());
+
+        // sacks and scrambles are not pass attempts; a scramble is a rush
+        assert!(!PlayResult9 { sack: true, ..pass }.is_pass_attempt());
+        let scramble = PlayResult9 { scramble: true, ..pass };
+        assert!(!scramble.is_pass_attempt() && scramble.is_rush());
+
+        let run = play(ScrimmagePlay9::Run);
+        assert!(run.is_rush() && !run.is_pass_attempt());
+
+        // a fumble is only a turnover when it is lost
+        assert!(!PlayResult9 { fumble: true, ..run }.is_turnover());
+        assert!(PlayResult9 { fumble: true, fumble_lost: true, ..run }.is_turnover());
+        assert!(PlayResult9 { interception: true, ..pass }.is_turnover());
+    }
+}
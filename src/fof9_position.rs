@@ -0,0 +1,295 @@
+use std::{fmt::Display, str::FromStr};
+
+use crate::fof9_playerdata::{PlayerPosition9, PlayerPositionGroup9};
+
+
+/// Every position or role shown by the game, on the field or on a roster
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Position {
+    QB,
+    RB,
+    FB,
+    TE,
+    FL,
+    SE,
+    WR,
+    C,
+    RG,
+    LG,
+    RT,
+    LT,
+    P,
+    K,
+    DLE,
+    DRE,
+    DLT,
+    DRT,
+    DT,
+    UT,
+    NT,
+    SILB,
+    WILB,
+    MLB,
+    SLB,
+    WLB,
+    RCB,
+    LCB,
+    NB,
+    DB,
+    SS,
+    FS,
+    LS,
+    PR,
+    KR,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum PositionUnit {
+    Offense,
+    Defense,
+    SpecialTeams,
+}
+
+impl Display for PositionUnit {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            PositionUnit::Offense => "Offense",
+            PositionUnit::Defense => "Defense",
+            PositionUnit::SpecialTeams => "Special Teams",
+        })
+    }
+}
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ParsePositionError(String);
+
+impl Display for ParsePositionError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "unknown position: {}", self.0)
+    }
+}
+
+impl std::error::Error for ParsePositionError {}
+
+impl Position {
+    pub const ALL: [Position; 35] = {
+        use Position::*;
+        [QB, RB, FB, TE, FL, SE, WR, C, RG, LG, RT, LT, P, K, DLE, DRE, DLT, DRT, DT, UT, NT,
+            SILB, WILB, MLB, SLB, WLB, RCB, LCB, NB, DB, SS, FS, LS, PR, KR]
+    };
+
+    pub fn name ( &self ) -> &'static str {
+        use Position::*;
+        match self {
+            QB => "QB", RB => "RB", FB => "FB", TE => "TE", FL => "FL", SE => "SE", WR => "WR",
+            C => "C", RG => "RG", LG => "LG", RT => "RT", LT => "LT",
+            P => "P", K => "K",
+            DLE => "DLE", DRE => "DRE", DLT => "DLT", DRT => "DRT", DT => "DT", UT => "UT", NT => "NT",
+            SILB => "SILB", WILB => "WILB", MLB => "MLB", SLB => "SLB", WLB => "WLB",
+            RCB => "RCB", LCB => "LCB", NB => "NB", DB => "DB", SS => "SS", FS => "FS",
+            LS => "LS", PR => "PR", KR => "KR",
+        }
+    }
+
+    /// None for the return roles, which are filled from any group
+    pub fn group ( &self ) -> Option<PlayerPositionGroup9> {
+        use Position::*;
+        match self {
+            QB => Some(PlayerPositionGroup9::QB),
+            RB => Some(PlayerPositionGroup9::RB),
+            FB => Some(PlayerPositionGroup9::FB),
+            TE => Some(PlayerPositionGroup9::TE),
+            FL | SE | WR => Some(PlayerPositionGroup9::WR),
+            C => Some(PlayerPositionGroup9::C),
+            LG | RG => Some(PlayerPositionGroup9::OG),
+            LT | RT => Some(PlayerPositionGroup9::OT),
+            P => Some(PlayerPositionGroup9::P),
+            K => Some(PlayerPositionGroup9::K),
+            DLE | DRE => Some(PlayerPositionGroup9::DE),
+            DLT | DRT | DT | UT | NT => Some(PlayerPositionGroup9::DT),
+            SILB | WILB | MLB => Some(PlayerPositionGroup9::ILB),
+            SLB | WLB => Some(PlayerPositionGroup9::OLB),
+            // NB and DB are the fifth and sixth defensive backs of the nickel and dime lists in FormationData9::blitz_position;
+            // the game does not say who fills them, so they go with the corners
+            RCB | LCB | NB | DB => Some(PlayerPositionGroup9::CB),
+            SS | FS => Some(PlayerPositionGroup9::S),
+            LS => Some(PlayerPositionGroup9::LS),
+            PR | KR => None,
+        }
+    }
+
+    pub fn unit ( &self ) -> PositionUnit {
+        match self.group() {
+            Some(group) => group.unit(),
+            None => PositionUnit::SpecialTeams,
+        }
+    }
+}
+
+impl Display for Position {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.name())
+    }
+}
+
+impl FromStr for Position {
+    type Err = ParsePositionError;
+
+    /// Accepts both naming schemes (DLE or LDE, and so on), in any case
+    fn from_str ( s: &str ) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_uppercase();
+        match name.as_str() {
+            "LDE" => Ok(Position::DLE),
+            "RDE" => Ok(Position::DRE),
+            "LDT" => Ok(Position::DLT),
+            "RDT" => Ok(Position::DRT),
+            _ => Position::ALL.iter().find(|p| p.name() == name).copied().ok_or(ParsePositionError(s.to_string())),
+        }
+    }
+}
+
+impl From<PlayerPosition9> for Position {
+    fn from ( position: PlayerPosition9 ) -> Self {
+        match position {
+            PlayerPosition9::QB => Position::QB,
+            PlayerPosition9::RB => Position::RB,
+            PlayerPosition9::FB => Position::FB,
+            PlayerPosition9::TE => Position::TE,
+            PlayerPosition9::FL => Position::FL,
+            PlayerPosition9::SE => Position::SE,
+            PlayerPosition9::LT => Position::LT,
+            PlayerPosition9::LG => Position::LG,
+            PlayerPosition9::C => Position::C,
+            PlayerPosition9::RG => Position::RG,
+            PlayerPosition9::RT => Position::RT,
+            PlayerPosition9::P => Position::P,
+            PlayerPosition9::K => Position::K,
+            PlayerPosition9::LDE => Position::DLE,
+            PlayerPosition9::LDT => Position::DLT,
+            PlayerPosition9::NT => Position::NT,
+            PlayerPosition9::RDT => Position::DRT,
+            PlayerPosition9::RDE => Position::DRE,
+            PlayerPosition9::SLB => Position::SLB,
+            PlayerPosition9::SILB => Position::SILB,
+            PlayerPosition9::MLB => Position::MLB,
+            PlayerPosition9::WILB => Position::WILB,
+            PlayerPosition9::WLB => Position::WLB,
+            PlayerPosition9::LCB => Position::LCB,
+            PlayerPosition9::RCB => Position::RCB,
+            PlayerPosition9::SS => Position::SS,
+            PlayerPosition9::FS => Position::FS,
+            PlayerPosition9::LS => Position::LS,
+        }
+    }
+}
+
+impl TryFrom<Position> for PlayerPosition9 {
+    type Error = Position;
+
+    /// Fails for the positions that are not roster positions (WR, DT, UT, NB, DB, PR, KR)
+    fn try_from ( position: Position ) -> Result<Self, Self::Error> {
+        PlayerPosition9::ALL.iter().find(|&&p| Position::from(p) == position).copied().ok_or(position)
+    }
+}
+
+impl From<PlayerPosition9> for PlayerPositionGroup9 {
+    fn from ( position: PlayerPosition9 ) -> Self {
+        position.group()
+    }
+}
+
+impl PlayerPosition9 {
+    pub const ALL: [PlayerPosition9; 28] = {
+        use PlayerPosition9::*;
+        [QB, RB, FB, TE, FL, SE, LT, LG, C, RG, RT, P, K, LDE, LDT, NT, RDT, RDE, SLB, SILB, MLB, WILB, WLB, LCB, RCB, SS, FS, LS]
+    };
+
+    pub fn group ( &self ) -> PlayerPositionGroup9 {
+        use PlayerPosition9::*;
+        match self {
+            QB => PlayerPositionGroup9::QB,
+            RB => PlayerPositionGroup9::RB,
+            FB => PlayerPositionGroup9::FB,
+            TE => PlayerPositionGroup9::TE,
+            FL | SE => PlayerPositionGroup9::WR,
+            LT | RT => PlayerPositionGroup9::OT,
+            LG | RG => PlayerPositionGroup9::OG,
+            C => PlayerPositionGroup9::C,
+            P => PlayerPositionGroup9::P,
+            K => PlayerPositionGroup9::K,
+            LDE | RDE => PlayerPositionGroup9::DE,
+            LDT | NT | RDT => PlayerPositionGroup9::DT,
+            SILB | MLB | WILB => PlayerPositionGroup9::ILB,
+            SLB | WLB => PlayerPositionGroup9::OLB,
+            LCB | RCB => PlayerPositionGroup9::CB,
+            SS | FS => PlayerPositionGroup9::S,
+            LS => PlayerPositionGroup9::LS,
+        }
+    }
+
+    pub fn unit ( &self ) -> PositionUnit {
+        self.group().unit()
+    }
+}
+
+impl FromStr for PlayerPosition9 {
+    type Err = ParsePositionError;
+
+    fn from_str ( s: &str ) -> Result<Self, Self::Err> {
+        s.parse::<Position>().and_then(|p| PlayerPosition9::try_from(p).map_err(|_| ParsePositionError(s.to_string())))
+    }
+}
+
+impl PlayerPositionGroup9 {
+    pub const ALL: [PlayerPositionGroup9; 17] = {
+        use PlayerPositionGroup9::*;
+        [QB, RB, FB, TE, WR, C, OG, OT, P, K, DE, DT, ILB, OLB, CB, S, LS]
+    };
+
+    pub fn unit ( &self ) -> PositionUnit {
+        use PlayerPositionGroup9::*;
+        match self {
+            QB | RB | FB | TE | WR | C | OG | OT => PositionUnit::Offense,
+            DE | DT | ILB | OLB | CB | S => PositionUnit::Defense,
+            P | K | LS => PositionUnit::SpecialTeams,
+        }
+    }
+
+    /// The roster positions in the group
+    pub fn positions ( &self ) -> Vec<PlayerPosition9> {
+        PlayerPosition9::ALL.iter().filter(|p| p.group() == *self).copied().collect()
+    }
+}
+
+impl FromStr for PlayerPositionGroup9 {
+    type Err = ParsePositionError;
+
+    fn from_str ( s: &str ) -> Result<Self, Self::Err> {
+        let name = s.trim().to_ascii_uppercase();
+        PlayerPositionGroup9::ALL.iter().find(|g| g.to_string() == name).copied().ok_or(ParsePositionError(s.to_string()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::{Position, PositionUnit};
+    use crate::fof9_playerdata::{PlayerPosition9, PlayerPositionGroup9};
+
+    #[test]
+    fn position_conversions () {
+        assert_eq!(Position::from(PlayerPosition9::LDE), Position::DLE);
+        assert_eq!(PlayerPosition9::try_from(Position::DRT), Ok(PlayerPosition9::RDT));
+        assert_eq!(PlayerPosition9::try_from(Position::NB), Err(Position::NB));
+        assert_eq!(PlayerPositionGroup9::from(PlayerPosition9::SILB), PlayerPositionGroup9::ILB);
+        assert_eq!("lde".parse::<Position>(), Ok(Position::DLE));
+        assert_eq!("DLE".parse::<PlayerPosition9>(), Ok(PlayerPosition9::LDE));
+        assert_eq!("olb".parse::<PlayerPositionGroup9>(), Ok(PlayerPositionGroup9::OLB));
+        assert!("XX".parse::<Position>().is_err());
+        assert_eq!(Position::KR.unit(), PositionUnit::SpecialTeams);
+        assert_eq!(PlayerPosition9::FS.unit(), PositionUnit::Defense);
+        assert_eq!(PlayerPositionGroup9::WR.positions(), vec![PlayerPosition9::FL, PlayerPosition9::SE]);
+        for position in PlayerPosition9::ALL {
+            assert_eq!(Position::from(position).group(), Some(position.group()));
+        }
+    }
+}
@@ -0,0 +1,80 @@
+use std::{collections::BTreeMap, fmt::Display, fs, path::Path};
+use log::{error, warn};
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CityInfo9 {
+    pub city: String,
+    pub state: String,
+}
+
+impl Display for CityInfo9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}, {}", self.city, self.state)
+    }
+}
+
+/// Lookup tables translating the hometown and college ids stored in players.dat
+// the id lists have not been extracted from the game yet, so tables have to be supplied by the user
+#[derive(Debug, Clone, Default)]
+pub struct ReferenceTables9 {
+    cities: BTreeMap<u32, CityInfo9>,
+    colleges: BTreeMap<u32, String>,
+}
+
+impl ReferenceTables9 {
+    /// Build tables from csv text: cities as `id,city,state`, colleges as `id,college`
+    pub fn from_csv ( cities: &str, colleges: &str ) -> Self {
+        ReferenceTables9 {
+            cities: csv_rows(cities, 3).filter_map(|(id, fields)| {
+                Some((id, CityInfo9 { city: fields.first()?.to_string(), state: fields.get(1)?.to_string() }))
+            }).collect(),
+            colleges: csv_rows(colleges, 2).filter_map(|(id, fields)| {
+                Some((id, fields.first()?.to_string()))
+            }).collect(),
+        }
+    }
+
+    /// Build tables from csv files, such as ones exported from a league's game data
+    pub fn from_files<P: AsRef<Path>> ( cities_path: P, colleges_path: P ) -> Option<Self> {
+        match (fs::read_to_string(cities_path.as_ref()), fs::read_to_string(colleges_path.as_ref())) {
+            (Ok(cities), Ok(colleges)) => Some(Self::from_csv(&cities, &colleges)),
+
+            (Err(err), _) | (_, Err(err)) => {
+                error!("unable to read reference tables: {}", err);
+                None
+            }
+        }
+    }
+
+    pub fn city ( &self, city_id: u32 ) -> Option<&CityInfo9> {
+        self.cities.get(&city_id)
+    }
+
+    pub fn college ( &self, college_id: u32 ) -> Option<&str> {
+        self.colleges.get(&college_id).map(|c| c.as_str())
+    }
+
+    pub fn number_cities ( &self ) -> usize {
+        self.cities.len()
+    }
+
+    pub fn number_colleges ( &self ) -> usize {
+        self.colleges.len()
+    }
+}
+
+// yields (id, remaining fields) for each data line, skipping blanks, comments and a header
+fn csv_rows ( text: &str, num_fields: usize ) -> impl Iterator<Item = (u32, Vec<&str>)> {
+    text.lines()
+        .map(|line| line.trim())
+        .filter(|line| !line.is_empty() && !line.starts_with('#'))
+        .filter_map(move |line| {
+            let fields: Vec<&str> = line.splitn(num_fields, ',').map(|field| field.trim().trim_matches('"')).collect();
+            match fields.first().map(|id| id.parse::<u32>()) {
+                Some(Ok(id)) if fields.len() == num_fields => Some((id, fields[1..].to_vec())),
+                Some(Ok(_)) => { warn!("reference table line has wrong number of fields: {}", line); None },
+                _ => None,  // header
+            }
+        })
+}
@@ -0,0 +1,234 @@
+use std::fmt::Display;
+
+use crate::fof9_weekdata::{Game9Data, GamePlay9, GamePlayInfo9};
+
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ScoreType9 {
+    Touchdown,
+    /// Interception or fumble returned by the defense
+    DefensiveTouchdown,
+    /// Kick or punt returned, or recovered in the end zone by the kicking team
+    ReturnTouchdown,
+    FieldGoal,
+    Safety,
+    ExtraPoint,
+    TwoPointConversion,
+}
+
+impl ScoreType9 {
+    pub fn points ( &self ) -> u32 {
+        match self {
+            ScoreType9::Touchdown | ScoreType9::DefensiveTouchdown | ScoreType9::ReturnTouchdown => 6,
+            ScoreType9::FieldGoal => 3,
+            ScoreType9::Safety | ScoreType9::TwoPointConversion => 2,
+            ScoreType9::ExtraPoint => 1,
+        }
+    }
+
+    pub fn is_touchdown ( &self ) -> bool {
+        matches!(self, ScoreType9::Touchdown | ScoreType9::DefensiveTouchdown | ScoreType9::ReturnTouchdown)
+    }
+}
+
+impl Display for ScoreType9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            ScoreType9::Touchdown => "Touchdown",
+            ScoreType9::DefensiveTouchdown => "Defensive Touchdown",
+            ScoreType9::ReturnTouchdown => "Return Touchdown",
+            ScoreType9::FieldGoal => "Field Goal",
+            ScoreType9::Safety => "Safety",
+            ScoreType9::ExtraPoint => "Extra Point",
+            ScoreType9::TwoPointConversion => "Two Point Conversion",
+        })
+    }
+}
+
+/// A play that put points on the board, with the score (home, away) after it
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct ScoringPlay9 {
+    pub play_index: usize,  // index into Game9Data::plays()
+    pub quarter: u32,
+    pub minutes_remaining: u32,
+    pub seconds_remaining: u32,
+    pub team: u32,  // 0 = home, 1 = away
+    pub score_type: ScoreType9,
+    pub score: (u32, u32),
+}
+
+impl ScoringPlay9 {
+    pub fn points ( &self ) -> u32 {
+        self.score_type.points()
+    }
+}
+
+impl Display for ScoringPlay9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}Q {}:{:02} {} {} ({}-{})", self.quarter, self.minutes_remaining, self.seconds_remaining,
+            if self.team == 0 { "home" } else { "away" }, self.score_type, self.score.0, self.score.1)
+    }
+}
+
+/// Disagreements between the points counted from the plays and the totals kept in the end of game section
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ScoreIssue9 {
+    RedZoneTouchdowns { team: u32, red_zone: u32, counted: u32 },
+    RedZoneFieldGoals { team: u32, red_zone: u32, counted: u32 },
+    ExtraPointsWithoutTouchdowns { team: u32, tries: u32, touchdowns: u32 },
+}
+
+impl Display for ScoreIssue9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            ScoreIssue9::RedZoneTouchdowns { team, red_zone, counted } => write!(f, "team {}: {} red zone touchdowns but {} counted from plays", team, red_zone, counted),
+            ScoreIssue9::RedZoneFieldGoals { team, red_zone, counted } => write!(f, "team {}: {} red zone field goals but {} counted from plays", team, red_zone, counted),
+            ScoreIssue9::ExtraPointsWithoutTouchdowns { team, tries, touchdowns } => write!(f, "team {}: {} extra point tries after {} touchdowns", team, tries, touchdowns),
+        }
+    }
+}
+
+/// The team (0 = home, 1 = away) scoring on a play, and how
+pub(crate) fn play_score ( info: &GamePlayInfo9 ) -> Option<(u32, ScoreType9)> {
+    let off = info.off_team;
+    let def = info.def_team();
+
+    match info.play {
+        GamePlay9::Run { result, .. } | GamePlay9::Pass { result, .. } => {
+            if result.touchdown() {
+                Some(if result.is_turnover() { (def, ScoreType9::DefensiveTouchdown) } else { (off, ScoreType9::Touchdown) })
+            } else if result.safety() {
+                Some((def, ScoreType9::Safety))
+            } else { None }
+        },
+
+        GamePlay9::FieldGoal { result, .. } | GamePlay9::Kickoff { result, .. } |
+        GamePlay9::OnsideKick { result, .. } | GamePlay9::Punt { result, .. } => {
+            if result.field_goal().is_some_and(|fg| fg.is_good()) {
+                Some((off, ScoreType9::FieldGoal))
+            } else if result.touchdown() {
+                Some((if result.recovering_team() == Some(off) { off } else { def }, ScoreType9::ReturnTouchdown))
+            } else { None }
+        },
+
+        GamePlay9::Special { .. } => {
+            info.play.extra_point().and_then(|extra_point| match extra_point.points() {
+                1 => Some((off, ScoreType9::ExtraPoint)),
+                2 => Some((off, ScoreType9::TwoPointConversion)),
+                _ => None,
+            })
+        },
+    }
+}
+
+/// Whether each play is a two point try: a run or pass after a touchdown, before the extra point result or the kickoff
+pub(crate) fn tries ( plays: &[GamePlayInfo9] ) -> Vec<bool> {
+    let mut after_touchdown = false;
+    plays.iter().map(|info| {
+        let is_try = after_touchdown && matches!(info.play, GamePlay9::Run { .. } | GamePlay9::Pass { .. });
+        if play_score(info).is_some_and(|(_, score_type)| score_type.is_touchdown()) {
+            after_touchdown = true;
+        } else if info.play.extra_point().is_some() || matches!(info.play, GamePlay9::Kickoff { .. } | GamePlay9::OnsideKick { .. }) {
+            after_touchdown = false;
+        }
+        is_try
+    }).collect()
+}
+
+/// The score on each play, leaving out two point tries: the touchdown flag on a try is already counted on the
+/// touchdown, and the try's points come from the extra point result after it
+pub(crate) fn play_scores ( plays: &[GamePlayInfo9] ) -> Vec<Option<(u32, ScoreType9)>> {
+    plays.iter().zip(tries(plays)).map(|(info, is_try)| if is_try { None } else { play_score(info) }).collect()
+}
+
+pub(crate) fn score_timeline ( game: &Game9Data ) -> Vec<(u32, u32)> {
+    let plays: Vec<_> = game.plays().collect();
+    let mut score = (0, 0);
+    play_scores(&plays).into_iter().map(|play_score| {
+        if let Some((team, score_type)) = play_score {
+            if team == 0 { score.0 += score_type.points() } else { score.1 += score_type.points() }
+        }
+        score
+    }).collect()
+}
+
+pub(crate) fn scoring_plays ( game: &Game9Data ) -> Vec<ScoringPlay9> {
+    let plays: Vec<_> = game.plays().collect();
+    let mut score = (0, 0);
+    plays.iter().zip(play_scores(&plays)).enumerate().filter_map(|(play_index, (info, play_score))| {
+        let (team, score_type) = play_score?;
+        if team == 0 { score.0 += score_type.points() } else { score.1 += score_type.points() }
+        Some(ScoringPlay9 {
+            play_index,
+            quarter: info.quarter,
+            minutes_remaining: info.minutes_remaining,
+            seconds_remaining: info.seconds_remaining,
+            team,
+            score_type,
+            score,
+        })
+    }).collect()
+}
+
+pub(crate) fn final_score ( game: &Game9Data ) -> (u32, u32) {
+    game.scoring_plays().last().map(|scoring| scoring.score).unwrap_or_default()
+}
+
+pub(crate) fn scoring_summary ( game: &Game9Data ) -> String {
+    game.scoring_plays().iter().map(|scoring| {
+        format!("{}Q {}:{:02} {:<4} {:<20} {:>3}-{:<3}\n", scoring.quarter, scoring.minutes_remaining, scoring.seconds_remaining,
+            game.team(scoring.team).short(), scoring.score_type.to_string(), scoring.score.0, scoring.score.1)
+    }).collect()
+}
+
+pub(crate) fn check_score ( game: &Game9Data ) -> Vec<ScoreIssue9> {
+    let mut issues = Vec::new();
+    let scoring = game.scoring_plays();
+
+    for team in 0..2 {
+        let count = |check: fn(&ScoreType9) -> bool| scoring.iter().filter(|s| s.team == team && check(&s.score_type)).count() as u32;
+        let touchdowns = count(|t| t.is_touchdown());
+        let offensive_touchdowns = count(|t| *t == ScoreType9::Touchdown);
+        let field_goals = count(|t| *t == ScoreType9::FieldGoal);
+
+        let possession = game.possession_stats(team);
+        let (red_zone_td, red_zone_fg) = (possession.red_zone_touchdowns(), possession.red_zone_field_goals());
+        if red_zone_td > offensive_touchdowns {
+            issues.push(ScoreIssue9::RedZoneTouchdowns { team, red_zone: red_zone_td, counted: offensive_touchdowns });
+        }
+        if red_zone_fg > field_goals {
+            issues.push(ScoreIssue9::RedZoneFieldGoals { team, red_zone: red_zone_fg, counted: field_goals });
+        }
+
+        let tries = game.plays().filter(|info| info.off_team == team && info.play.extra_point().is_some()).count() as u32;
+        if tries > touchdowns {
+            issues.push(ScoreIssue9::ExtraPointsWithoutTouchdowns { team, tries, touchdowns });
+        }
+    }
+    issues
+}
+
+#[cfg(test)]
+mod tests {
+    use crate::{fof9_testdata::{extra_point_play, kickoff_play, scrimmage_play}, fof9_weekdata::{GamePlay9, GamePlayInfo9}};
+
+    use super::{play_scores, tries, ScoreType9};
+
+    fn info ( play: &GamePlay9 ) -> GamePlayInfo9<'_> {
+        GamePlayInfo9 { quarter: 1, minutes_remaining: 10, seconds_remaining: 0, off_team: 0, down: 1, yards_to_go: 10, yardline: 95,
+            home_timeouts: 3, away_timeouts: 3, play }
+    }
+
+    #[test]
+    fn two_point_try_is_scored_once () {
+        let touchdown: GamePlay9 = scrimmage_play(5, true).read();
+        let try_run: GamePlay9 = scrimmage_play(5, true).read();
+        let conversion: GamePlay9 = extra_point_play([0, 0, 0, 1, 1, 0, 0]).read();
+        let kickoff: GamePlay9 = kickoff_play().read();
+        let plays = [info(&touchdown), info(&try_run), info(&conversion), info(&kickoff)];
+
+        assert_eq!(tries(&plays), [false, true, false, false]);
+        assert_eq!(play_scores(&plays), [Some((0, ScoreType9::Touchdown)), None, Some((0, ScoreType9::TwoPointConversion)), None]);
+        assert_eq!(play_scores(&plays).iter().flatten().map(|(_, score_type)| score_type.points()).sum::<u32>(), 8);
+    }
+}
@@ -0,0 +1,256 @@
+use std::{collections::BTreeMap, fmt::{Display, Write}};
+
+use crate::{fof9_utility::csv_field, fof9_weekdata::{GamePlay9, GamePlayInfo9, Week9Data}};
+
+
+/// How plays are grouped down the side of a tendency table
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Situation9 {
+    Down,
+    Distance,
+    FieldZone,
+    Quarter,
+    /// Offensive personnel on the field, for defensive reports
+    Personnel,
+}
+
+impl Situation9 {
+    pub const ALL: [Situation9; 4] = [Situation9::Down, Situation9::Distance, Situation9::FieldZone, Situation9::Quarter];
+
+    /// Every bucket, in display order
+    pub fn buckets ( &self ) -> &'static [&'static str] {
+        match self {
+            Situation9::Down => &["1st", "2nd", "3rd", "4th"],
+            Situation9::Distance => &["1-3", "4-6", "7-10", "11+"],
+            Situation9::FieldZone => &["Own 1-20", "Own 21-50", "Opp 49-21", "Red Zone"],
+            Situation9::Quarter => &["1Q", "2Q", "3Q", "4Q", "OT"],
+            // the OffensivePersonnel9 groupings, with Other so that every play counted lands in a row
+            Situation9::Personnel => &["005", "014", "014t", "023", "104", "113", "113t", "122", "131", "203", "212", "221", "230", "Other"],
+        }
+    }
+
+    pub fn bucket ( &self, info: &GamePlayInfo9 ) -> &'static str {
+        let buckets = self.buckets();
+        buckets[match self {
+            Situation9::Down => info.down.clamp(1, 4) as usize - 1,
+            Situation9::Distance => match info.yards_to_go {
+                0..=3 => 0,
+                4..=6 => 1,
+                7..=10 => 2,
+                _ => 3,
+            },
+            Situation9::FieldZone => match info.yards_to_goal() {
+                80.. => 0,
+                50..=79 => 1,
+                21..=49 => 2,
+                _ => 3,
+            },
+            Situation9::Quarter => info.quarter.clamp(1, 5) as usize - 1,
+            Situation9::Personnel => {
+                let personnel = info.play.formation().map(|formation| formation.offensive_personnel().to_string()).unwrap_or_default();
+                buckets.iter().position(|&bucket| bucket == personnel).unwrap_or(buckets.len() - 1)
+            },
+        }]
+    }
+}
+
+impl Display for Situation9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", match self {
+            Situation9::Down => "Down",
+            Situation9::Distance => "Distance",
+            Situation9::FieldZone => "Field Zone",
+            Situation9::Quarter => "Quarter",
+            Situation9::Personnel => "Personnel",
+        })
+    }
+}
+
+/// Counts of one kind of call (play type, formation, ...) in each bucket of a situation
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TendencyTable9 {
+    pub situation: Situation9,
+    pub category: &'static str,
+    pub rows: Vec<(&'static str, BTreeMap<String, u32>)>,
+}
+
+impl TendencyTable9 {
+    pub fn new ( situation: Situation9, category: &'static str ) -> Self {
+        TendencyTable9 { situation, category, rows: situation.buckets().iter().map(|&bucket| (bucket, BTreeMap::new())).collect() }
+    }
+
+    pub fn add ( &mut self, bucket: &str, choice: &str ) {
+        if let Some((_, row)) = self.rows.iter_mut().find(|(b, _)| *b == bucket) {
+            *row.entry(choice.to_string()).or_default() += 1;
+        }
+    }
+
+    pub fn count ( &self, bucket: &str, choice: &str ) -> u32 {
+        self.rows.iter().find(|(b, _)| *b == bucket).and_then(|(_, row)| row.get(choice).copied()).unwrap_or_default()
+    }
+
+    pub fn total ( &self, bucket: &str ) -> u32 {
+        self.rows.iter().find(|(b, _)| *b == bucket).map(|(_, row)| row.values().sum()).unwrap_or_default()
+    }
+
+    /// Percent of the plays in the bucket
+    pub fn share ( &self, bucket: &str, choice: &str ) -> f64 {
+        match self.total(bucket) {
+            0 => 0.0,
+            total => 100.0 * f64::from(self.count(bucket, choice)) / f64::from(total),
+        }
+    }
+
+    /// Every choice seen in any bucket
+    pub fn choices ( &self ) -> Vec<String> {
+        let mut choices: Vec<String> = self.rows.iter().flat_map(|(_, row)| row.keys().cloned()).collect();
+        choices.sort();
+        choices.dedup();
+        choices
+    }
+}
+
+impl Display for TendencyTable9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let choices = self.choices();
+        write!(f, "{} by {}\n{:<10}{:>6}", self.category, self.situation, "", "Plays")?;
+        for choice in &choices {
+            write!(f, "{:>12}", choice)?;
+        }
+        writeln!(f)?;
+        for (bucket, _) in &self.rows {
+            write!(f, "{:<10}{:>6}", bucket, self.total(bucket))?;
+            for choice in &choices {
+                write!(f, "{:>11.1}%", self.share(bucket, choice))?;
+            }
+            writeln!(f)?;
+        }
+        Ok(())
+    }
+}
+
+/// Tendency tables for one side of a team's plays
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ScoutingReport9 {
+    pub team: u32,  // league team number
+    pub side: &'static str,
+    pub plays: u32,
+    pub tables: Vec<TendencyTable9>,
+    pub blitzers: BTreeMap<String, u32>,  // plays each position blitzed, defense only
+}
+
+impl ScoutingReport9 {
+    /// Run/pass split, formation and personnel for every play the team ran on offense
+    pub fn offense ( weeks: &[Week9Data], team: u32 ) -> Self {
+        let categories = ["Play Type", "Formation", "Personnel"];
+        Self::build(weeks, team, true, &categories, &Situation9::ALL, |info| {
+            let formation = info.play.formation()?;
+            let play_type = match info.play {
+                GamePlay9::Run { .. } => "Run",
+                _ => "Pass",
+            };
+            Some(vec![play_type.to_string(), formation.offensive_formation().to_string(), formation.offensive_personnel().to_string()])
+        })
+    }
+
+    /// Front, coverage, personnel, blitz and spy calls for every play the team defended, also split by the personnel faced
+    pub fn defense ( weeks: &[Week9Data], team: u32 ) -> Self {
+        let categories = ["Front", "Coverage", "Personnel", "Blitzers", "Spy"];
+        let situations = [Situation9::Down, Situation9::Distance, Situation9::Personnel];
+        let mut report = Self::build(weeks, team, false, &categories, &situations, |info| {
+            let formation = info.play.formation()?;
+            let blitzers = match info.play.defensive_blitzers().len() {
+                count @ 0..=3 => count.to_string(),
+                _ => "4+".to_string(),
+            };
+            let spy = if info.play.defensive_spies().is_empty() { "No Spy" } else { "Spy" };
+            Some(vec![formation.defensive_front().to_string(), formation.defensive_coverage().to_string(),
+                formation.defensive_personnel().to_string(), blitzers, spy.to_string()])
+        });
+
+        for info in side_plays(weeks, team, false) {
+            if let Some(formation) = info.play.formation() {
+                for &blitzer in info.play.defensive_blitzers() {
+                    *report.blitzers.entry(formation.blitz_position(blitzer)).or_default() += 1;
+                }
+            }
+        }
+        report
+    }
+
+    /// Tables for each category and situation, from the category values returned for each play in the order of categories
+    fn build<F> ( weeks: &[Week9Data], team: u32, offense: bool, categories: &[&'static str], situations: &[Situation9], choices: F ) -> Self
+        where F: Fn(&GamePlayInfo9) -> Option<Vec<String>> {
+        let mut tables: Vec<TendencyTable9> = categories.iter()
+            .flat_map(|&category| situations.iter().map(move |&situation| TendencyTable9::new(situation, category)))
+            .collect();
+        let mut plays = 0;
+
+        for info in side_plays(weeks, team, offense) {
+            let Some(values) = choices(&info) else { continue };
+            plays += 1;
+            for table in tables.iter_mut() {
+                if let Some(index) = categories.iter().position(|&c| c == table.category) {
+                    table.add(table.situation.bucket(&info), &values[index]);
+                }
+            }
+        }
+
+        ScoutingReport9 { team, side: if offense { "Offense" } else { "Defense" }, plays, tables, blitzers: BTreeMap::new() }
+    }
+
+    /// Share of plays with a blitz from the position, as blitz_position names it
+    pub fn blitz_rate ( &self, position: &str ) -> f64 {
+        if self.plays == 0 { 0.0 } else { 100.0 * f64::from(self.blitzers.get(position).copied().unwrap_or_default()) / f64::from(self.plays) }
+    }
+
+    pub fn table ( &self, category: &str, situation: Situation9 ) -> Option<&TendencyTable9> {
+        self.tables.iter().find(|table| table.category == category && table.situation == situation)
+    }
+
+    pub fn to_text ( &self ) -> String {
+        let mut out = format!("{} tendencies, team {}, {} plays\n", self.side, self.team, self.plays);
+        for table in &self.tables {
+            let _ = write!(out, "\n{}", table);
+        }
+        if !self.blitzers.is_empty() {
+            let mut blitzers: Vec<(&String, &u32)> = self.blitzers.iter().collect();
+            blitzers.sort_by_key(|(_, count)| std::cmp::Reverse(**count));
+            let _ = writeln!(out, "\nBlitzers");
+            for (position, count) in blitzers {
+                let _ = writeln!(out, "{:<10}{:>6}{:>11.1}%", position, count, self.blitz_rate(position));
+            }
+        }
+        out
+    }
+
+    /// One row per team, situation, bucket and choice, with a header row
+    pub fn to_csv ( &self ) -> String {
+        let mut out = String::from("team,side,category,situation,bucket,choice,plays,share\n");
+        for table in &self.tables {
+            for (bucket, row) in &table.rows {
+                for (choice, count) in row {
+                    let _ = writeln!(out, "{},{},{},{},{},{},{},{:.1}", self.team, self.side, table.category, table.situation,
+                        bucket, csv_field(choice), count, table.share(bucket, choice));
+                }
+            }
+        }
+        for (position, count) in &self.blitzers {
+            let _ = writeln!(out, "{},{},Blitzer,All,All,{},{},{:.1}", self.team, self.side, position, count, self.blitz_rate(position));
+        }
+        out
+    }
+}
+
+impl Display for ScoutingReport9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", self.to_text())
+    }
+}
+
+/// Every play where the team was on offense, or on defense
+fn side_plays ( weeks: &[Week9Data], team: u32, offense: bool ) -> impl Iterator<Item = GamePlayInfo9<'_>> {
+    weeks.iter().flat_map(|week| week.games.iter()).flat_map(move |game| {
+        game.plays().filter(move |info| game.team(if offense { info.off_team } else { info.def_team() }).number() == team)
+    })
+}
@@ -0,0 +1,102 @@
+use std::fmt::Display;
+
+use crate::{fof9_playresult::{PassDepth9, RunDirection9}, fof9_weekdata::{Game9Data, PassPlayStats9, PassStats9, PossessionStats9, RunPlayStats9, RunStats9, Week9Data}};
+
+
+/// A team's end of game totals, for one game or added up over several
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq)]
+pub struct TeamGameSummary9 {
+    pub team: u32,  // league team number
+    pub games: u32,
+    pub pass: PassStats9,
+    pub run: RunStats9,
+    pub possession: PossessionStats9,
+}
+
+impl TeamGameSummary9 {
+    /// All the games a team played in the given weeks
+    pub fn season ( weeks: &[Week9Data], team: u32 ) -> Self {
+        let mut summary = TeamGameSummary9 { team, ..Default::default() };
+        for game in weeks.iter().flat_map(|week| week.games.iter()) {
+            for game_team in 0..2 {
+                if game.team(game_team).number() == team {
+                    summary.combine(&game.team_summary(game_team));
+                }
+            }
+        }
+        summary
+    }
+
+    /// Adds another game, or another set of games, for the same team
+    pub fn combine ( &mut self, other: &TeamGameSummary9 ) {
+        self.games += other.games;
+        self.pass.add(&other.pass);
+        self.run.add(&other.run);
+        self.possession.add(&other.possession);
+    }
+
+    pub fn passing ( &self ) -> PassPlayStats9 {
+        self.pass.total()
+    }
+
+    pub fn passing_by_depth ( &self, depth: PassDepth9 ) -> PassPlayStats9 {
+        self.pass.depth(depth)
+    }
+
+    pub fn rushing ( &self ) -> RunPlayStats9 {
+        self.run.total()
+    }
+
+    pub fn rushing_by_direction ( &self, direction: RunDirection9 ) -> RunPlayStats9 {
+        self.run.direction(direction)
+    }
+
+    pub fn completion_pct ( &self ) -> f64 {
+        self.passing().completion_pct()
+    }
+
+    pub fn yards_per_attempt ( &self ) -> f64 {
+        self.passing().yards_per_attempt()
+    }
+
+    pub fn yards_per_carry ( &self ) -> f64 {
+        self.rushing().yards_per_carry()
+    }
+
+    pub fn red_zone_td_pct ( &self ) -> f64 {
+        self.possession.red_zone_td_pct()
+    }
+
+    pub fn total_yards ( &self ) -> i32 {
+        self.passing().yards() + self.rushing().yards()
+    }
+
+    /// Seconds per game
+    pub fn time_of_possession ( &self ) -> f64 {
+        if self.games == 0 { 0.0 } else { f64::from(self.possession.seconds()) / f64::from(self.games) }
+    }
+}
+
+impl Display for TeamGameSummary9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        let passing = self.passing();
+        let rushing = self.rushing();
+        let possession = self.time_of_possession() as u32;
+        write!(f, "team {} ({} games): passing {}/{} {} yds ({:.1}%, {:.1} Y/A), rushing {}-{} ({:.1} YPC), red zone {}/{} td ({:.1}%), possession {}:{:02} per game",
+            self.team, self.games,
+            passing.completions(), passing.attempts(), passing.yards(), self.completion_pct(), self.yards_per_attempt(),
+            rushing.attempts(), rushing.yards(), self.yards_per_carry(),
+            self.possession.red_zone_touchdowns(), self.possession.red_zone_attempts(), self.red_zone_td_pct(),
+            possession / 60, possession % 60)
+    }
+}
+
+pub(crate) fn team_summary ( game: &Game9Data, game_team: u32 ) -> TeamGameSummary9 {
+    TeamGameSummary9 {
+        team: game.team(game_team).number(),
+        games: 1,
+        pass: *game.pass_stats(game_team),
+        run: *game.run_stats(game_team),
+        possession: *game.possession_stats(game_team),
+    }
+}
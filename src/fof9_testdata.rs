@@ -0,0 +1,89 @@
+//! Byte builders for the unit tests, laid out the way the save files store them
+
+use std::io::Cursor;
+use binrw::{BinRead, BinReaderExt};
+
+use crate::fof9_playresult::PLAY_TOUCHDOWN;
+
+#[derive(Default)]
+pub(crate) struct TestBytes {
+    bytes: Vec<u8>,
+}
+
+impl TestBytes {
+    pub(crate) fn new () -> Self {
+        Self::default()
+    }
+
+    pub(crate) fn u32 ( mut self, value: u32 ) -> Self {
+        self.bytes.extend(value.to_le_bytes());
+        self
+    }
+
+    pub(crate) fn u32s<I: IntoIterator<Item = u32>> ( mut self, values: I ) -> Self {
+        self.bytes.extend(values.into_iter().flat_map(u32::to_le_bytes));
+        self
+    }
+
+    /// Length prefixed, as FixedString reads it
+    pub(crate) fn string ( self, text: &str ) -> Self {
+        let mut this = self.u32(text.len() as u32);
+        this.bytes.extend(text.as_bytes());
+        this
+    }
+
+    pub(crate) fn zeros ( mut self, count: usize ) -> Self {
+        self.bytes.extend(std::iter::repeat_n(0u8, count));
+        self
+    }
+
+    /// Parse the bytes, checking that the record used all of them
+    pub(crate) fn read<T> ( &self ) -> T where for<'a> T: BinRead<Args<'a> = ()> {
+        let mut cursor = Cursor::new(&self.bytes);
+        let value = cursor.read_le().expect("test record did not parse");
+        assert_eq!(cursor.position() as usize, self.bytes.len(), "test record has unread bytes");
+        value
+    }
+}
+
+/// A player record with empty history lists and every rating set to `rating`
+pub(crate) fn player_record ( player_id: u32, position: u32, team: u32, jersey: u32, rating: u32 ) -> TestBytes {
+    TestBytes::new()
+        .u32(player_id)
+        .string("Test").string("").string("Player")
+        .u32s([position, 1, 0, 3])  // position, group (any valid), some_1, experience
+        .u32s([0; 9 + 2])
+        .u32s([600, 80, 256, 220])  // height, hand, arm, weight
+        .u32s([1995, 6, 15, 0, 0, 0, 0])  // birth, home, colleges
+        .u32s([2017, 0, 10, 1, 10, 0, 3, 0, 0, 0])  // draft
+        .u32s([jersey, team])
+        .u32s([0; 116 + 2 + 7 + 3])  // data_1, years, empty lists, what_*
+        .u32s([rating; 64 * 3])
+        .u32s([0; 48 + 1])
+}
+
+/// A run (5) or pass (6) with an empty formation, no penalty or injury, and only the touchdown flag set
+pub(crate) fn scrimmage_play ( play_type: u32, touchdown: bool ) -> TestBytes {
+    let mut data = [0; 373];
+    data[PLAY_TOUCHDOWN] = u32::from(touchdown);
+    TestBytes::new()
+        .u32(play_type)
+        .u32s([0; 7 + 1 + 1 + 10])  // formation, start_drive, blitz count and assignments
+        .u32s([0; 20 + 7 + 2])  // penalty, unknown, injury
+        .u32s(data)
+}
+
+pub(crate) fn kickoff_play () -> TestBytes {
+    TestBytes::new().u32(2).u32s([0; 421])
+}
+
+/// A special play for the extra point, with the seven values of its ExtraPointResult9
+pub(crate) fn extra_point_play ( extra_point: [u32; 7] ) -> TestBytes {
+    TestBytes::new()
+        .u32(7)
+        .u32s([0; 294])
+        .u32s(extra_point)
+        .u32s([0; 3])
+        .u32(0)  // SpecialPlay9::ExtraPoint
+        .u32s([0; 116])
+}
@@ -56,6 +56,13 @@ impl Display for LengthInches {
     }
 }
 
+/// Quotes a field for csv output when it needs it
+pub(crate) fn csv_field ( field: &str ) -> String {
+    if field.contains([',', '"', '\n']) {
+        format!("\"{}\"", field.replace('"', "\"\""))
+    } else { field.to_string() }
+}
+
 fn fmt_inches ( inches: u32, eighths: u32 ) -> String {
     if inches > 0 {
         format!{" {}{}\"",
@@ -97,3 +104,33 @@ impl Display for Date {
         )
     }
 }
+
+impl Date {
+    pub fn year ( &self ) -> u32 {
+        self.year
+    }
+
+    pub fn month ( &self ) -> u32 {
+        self.month
+    }
+
+    pub fn day ( &self ) -> u32 {
+        self.day
+    }
+
+    pub fn is_valid ( &self ) -> bool {
+        let days = match self.month {
+            1 | 3 | 5 | 7 | 8 | 10 | 12 => 31,
+            4 | 6 | 9 | 11 => 30,
+            2 if self.year % 4 == 0 && (self.year % 100 != 0 || self.year % 400 == 0) => 29,
+            2 => 28,
+            _ => 0,
+        };
+        self.year > 0 && (1..=days).contains(&self.day)
+    }
+
+    /// Age reached during the given year
+    pub fn age ( &self, current_year: u32 ) -> u32 {
+        current_year.saturating_sub(self.year)
+    }
+}
@@ -0,0 +1,182 @@
+use std::{collections::BTreeMap, fmt::Display};
+
+use crate::{fof9_leaguedata::League9Data, fof9_playerdata::{Player9Data, Players9Data}};
+
+const DEFAULT_MAX_ROSTER_SIZE: usize = 90;  // offseason limit, the regular season one is 53
+const MIN_AGE: u32 = 18;
+const MAX_AGE: u32 = 50;
+
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum RosterIssue9 {
+    DuplicateJersey { team: usize, jersey: u32, player_ids: Vec<u32> },
+    JerseyWithoutTeam { player_id: u32, jersey: u32 },
+    RosterTooLarge { team: usize, size: usize, limit: usize },
+    UnknownTeam { player_id: u32, team: usize },
+    NotInTeamList { player_id: u32, team: usize },
+    ListedOnOtherTeam { player_id: u32, listed_team: usize, player_team: Option<usize> },
+    ListedUnknownPlayer { team: usize, player_id: u32 },
+    BadBirthDate { player_id: u32, birth: String },
+    UnlikelyAge { player_id: u32, age: u32 },
+    BadDraft { player_id: u32, reason: &'static str },
+}
+
+impl Display for RosterIssue9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            RosterIssue9::DuplicateJersey { team, jersey, player_ids } => write!(f, "team {}: jersey {} worn by players {:?}", team, jersey, player_ids),
+            RosterIssue9::JerseyWithoutTeam { player_id, jersey } => write!(f, "player {}: jersey {} without a team", player_id, jersey),
+            RosterIssue9::RosterTooLarge { team, size, limit } => write!(f, "team {}: {} players, over the limit of {}", team, size, limit),
+            RosterIssue9::UnknownTeam { player_id, team } => write!(f, "player {}: unknown team {}", player_id, team),
+            RosterIssue9::NotInTeamList { player_id, team } => write!(f, "player {}: missing from team {} list", player_id, team),
+            RosterIssue9::ListedOnOtherTeam { player_id, listed_team, player_team } => write!(f, "player {}: listed by team {} but with {}", player_id, listed_team,
+                if let Some(team) = player_team { format!("team {}", team) } else { "no team".to_string() }),
+            RosterIssue9::ListedUnknownPlayer { team, player_id } => write!(f, "team {}: lists unknown player {}", team, player_id),
+            RosterIssue9::BadBirthDate { player_id, birth } => write!(f, "player {}: impossible birth date {}", player_id, birth),
+            RosterIssue9::UnlikelyAge { player_id, age } => write!(f, "player {}: unlikely age {}", player_id, age),
+            RosterIssue9::BadDraft { player_id, reason } => write!(f, "player {}: bad draft data, {}", player_id, reason),
+        }
+    }
+}
+
+/// Consistency checks across players.dat and league.dat, to catch corruption or a misaligned parse
+#[derive(Debug, Clone)]
+pub struct RosterValidator9 {
+    max_roster_size: usize,
+    current_year: Option<u32>,
+}
+
+impl Default for RosterValidator9 {
+    fn default ( ) -> Self {
+        RosterValidator9 { max_roster_size: DEFAULT_MAX_ROSTER_SIZE, current_year: None }
+    }
+}
+
+impl RosterValidator9 {
+    pub fn new ( ) -> Self {
+        Self::default()
+    }
+
+    /// The default allows offseason rosters, use 53 to check after final cuts
+    pub fn max_roster_size ( mut self, max_roster_size: usize ) -> Self {
+        self.max_roster_size = max_roster_size;
+        self
+    }
+
+    /// Enables the age and draft year checks
+    pub fn current_year ( mut self, current_year: u32 ) -> Self {
+        self.current_year = Some(current_year);
+        self
+    }
+
+    pub fn validate ( &self, players: &Players9Data, league: &League9Data ) -> Vec<RosterIssue9> {
+        let mut issues = Vec::new();
+        self.check_rosters(players, &mut issues);
+        self.check_team_lists(players, league, &mut issues);
+        for player in players.players() {
+            self.check_birth(player, &mut issues);
+            self.check_draft(player, league, &mut issues);
+        }
+        issues
+    }
+
+    fn check_rosters ( &self, players: &Players9Data, issues: &mut Vec<RosterIssue9> ) {
+        let mut rosters = BTreeMap::<usize, Vec<&Player9Data>>::new();
+        for player in players.players() {
+            match player.team_id() {
+                Some(team) => rosters.entry(team).or_default().push(player),
+                None => {
+                    let jersey = player.stored_jersey_number();
+                    if jersey != 0 {
+                        issues.push(RosterIssue9::JerseyWithoutTeam { player_id: player.player_id(), jersey });
+                    }
+                },
+            }
+        }
+
+        for (&team, roster) in &rosters {
+            if roster.len() > self.max_roster_size {
+                issues.push(RosterIssue9::RosterTooLarge { team, size: roster.len(), limit: self.max_roster_size });
+            }
+
+            let mut jerseys = BTreeMap::<u32, Vec<u32>>::new();
+            for player in roster {
+                jerseys.entry(player.stored_jersey_number()).or_default().push(player.player_id());
+            }
+            issues.extend(jerseys.into_iter().filter(|(_, ids)| ids.len() > 1)
+                .map(|(jersey, player_ids)| RosterIssue9::DuplicateJersey { team, jersey, player_ids }));
+        }
+    }
+
+    fn check_team_lists ( &self, players: &Players9Data, league: &League9Data, issues: &mut Vec<RosterIssue9> ) {
+        let team_lists: BTreeMap<usize, Vec<u32>> = league.teams.iter()
+            .map(|team| (team.team_number as usize, team.team_players.iter().copied().filter(|&id| id != 0).collect()))
+            .collect();
+
+        for player in players.players() {
+            if let Some(team) = player.team_id() {
+                match team_lists.get(&team) {
+                    Some(list) if !list.contains(&player.player_id()) => {
+                        issues.push(RosterIssue9::NotInTeamList { player_id: player.player_id(), team });
+                    },
+                    Some(_) => {},
+                    None => {
+                        issues.push(RosterIssue9::UnknownTeam { player_id: player.player_id(), team });
+                    },
+                }
+            }
+        }
+
+        for (&listed_team, list) in &team_lists {
+            for &player_id in list {
+                match players.player_data(player_id) {
+                    Some(player) if player.team_id() != Some(listed_team) => {
+                        issues.push(RosterIssue9::ListedOnOtherTeam { player_id, listed_team, player_team: player.team_id() });
+                    },
+                    Some(_) => {},
+                    None => {
+                        issues.push(RosterIssue9::ListedUnknownPlayer { team: listed_team, player_id });
+                    },
+                }
+            }
+        }
+    }
+
+    fn check_birth ( &self, player: &Player9Data, issues: &mut Vec<RosterIssue9> ) {
+        let birth = player.birth();
+        if !birth.is_valid() {
+            issues.push(RosterIssue9::BadBirthDate { player_id: player.player_id(), birth: birth.to_string() });
+        } else if let Some(year) = self.current_year {
+            let age = player.age(year);
+            if !(MIN_AGE..=MAX_AGE).contains(&age) {
+                issues.push(RosterIssue9::UnlikelyAge { player_id: player.player_id(), age });
+            }
+        }
+    }
+
+    fn check_draft ( &self, player: &Player9Data, league: &League9Data, issues: &mut Vec<RosterIssue9> ) {
+        let player_id = player.player_id();
+        let mut bad = |reason| issues.push(RosterIssue9::BadDraft { player_id, reason });
+
+        // a bad birth date has already been reported
+        let birth = player.birth();
+        if birth.is_valid() && player.draft_year() < birth.year().saturating_add(MIN_AGE) {
+            bad("draft year too soon after birth");
+        }
+        if self.current_year.is_some_and(|year| player.draft_year() > year.saturating_add(1)) {
+            bad("draft year in the future");
+        }
+
+        if let Some(draft) = player.draft() {
+            if draft.round == 0 || draft.pick == 0 || draft.overall == 0 {
+                bad("drafted without round or pick");
+            }
+            if draft.overall < draft.pick || draft.overall < draft.round {
+                bad("overall pick before pick in round");
+            }
+            if draft.team >= league.number_teams {
+                bad("drafted by unknown team");
+            }
+        }
+    }
+}
@@ -1,8 +1,12 @@
 use std::fmt::Display;
 use num_traits::FromPrimitive;
 use binrw::{BinRead, helpers::{until_eof, until}, binread};
+use log::error;
 
-use crate::{fof9_utility::FixedString, Position};
+use crate::{fof9_drive::{self, Drive9, DriveSummary9}, fof9_environment::{self, GameEnvironment9}, fof9_expectedpoints::{self, ExpectedPoints9, PlayValue9},
+    fof9_injury::{self, GameInjury9}, fof9_penalty::{self, GamePenalty9, PenaltyOutcome9, PenaltyType9}, fof9_playerdata::Players9Data,
+    fof9_playresult::{KickResult9, KickType9, PassDepth9, PlayResult9, RunDirection9, ScrimmagePlay9}, fof9_scoring::{self, ScoreIssue9, ScoringPlay9},
+    fof9_teamsummary::{self, TeamGameSummary9}, fof9_utility::FixedString, fof9_winprobability::{self, PlayWinProbability9, WinProbability9}, Position};
 
 const NUM_BLITZERS: usize = 10;
 
@@ -48,10 +52,188 @@ impl Game9Data {
         }
     }
 
+    pub fn year ( &self ) -> u32 {
+        if let Game9Section::Start{year, ..} = self.sections.first().unwrap() {
+            *year
+        } else {
+            panic!("first section of game is not start");
+        }
+    }
+
+    pub fn week ( &self ) -> u32 {
+        if let Game9Section::Start{current_week, ..} = self.sections.first().unwrap() {
+            *current_week
+        } else {
+            panic!("first section of game is not start");
+        }
+    }
+
+    /// The plays of the game, with the situation before each; plays with an offensive team other than 0 or 1 are skipped
+    pub fn plays ( &self ) -> impl Iterator<Item = GamePlayInfo9<'_>> {
+        self.sections.iter().filter_map(|section| {
+            if let Game9Section::Play { quarter, minutes_remaining, seconds_remaining, off_team, down, yards_to_go, yardline, home_timeouts, away_timeouts, play } = section {
+                if *off_team > 1 {
+                    error!("play with offensive team {}: {}", off_team, section);
+                    return None;
+                }
+                Some(GamePlayInfo9 {
+                    quarter: *quarter,
+                    minutes_remaining: *minutes_remaining,
+                    seconds_remaining: *seconds_remaining,
+                    off_team: *off_team,
+                    down: *down,
+                    yards_to_go: *yards_to_go,
+                    yardline: *yardline,
+                    home_timeouts: *home_timeouts,
+                    away_timeouts: *away_timeouts,
+                    play,
+                })
+            } else { None }
+        })
+    }
+
+    pub fn pass_stats ( &self, game_team: u32 ) -> &PassStats9 {
+        assert!(game_team < 2);
+        if let Game9Section::End{home_pass_stats, away_pass_stats, ..} = self.sections.last().unwrap() {
+            [home_pass_stats, away_pass_stats][usize::from_u32(game_team).unwrap()]
+        } else {
+            panic!("last section of game is not end");
+        }
+    }
+
+    pub fn run_stats ( &self, game_team: u32 ) -> &RunStats9 {
+        assert!(game_team < 2);
+        if let Game9Section::End{home_run_stats, away_run_stats, ..} = self.sections.last().unwrap() {
+            [home_run_stats, away_run_stats][usize::from_u32(game_team).unwrap()]
+        } else {
+            panic!("last section of game is not end");
+        }
+    }
+
+    pub fn possession_stats ( &self, game_team: u32 ) -> &PossessionStats9 {
+        assert!(game_team < 2);
+        if let Game9Section::End{home_possession, away_possession, ..} = self.sections.last().unwrap() {
+            [home_possession, away_possession][usize::from_u32(game_team).unwrap()]
+        } else {
+            panic!("last section of game is not end");
+        }
+    }
+
+    pub(crate) fn drive_info ( &self, game_team: u32 ) -> &[DriveInfo9] {
+        assert!(game_team < 2);
+        if let Game9Section::End{home_drive, away_drive, ..} = self.sections.last().unwrap() {
+            [home_drive, away_drive][usize::from_u32(game_team).unwrap()]
+        } else {
+            panic!("last section of game is not end");
+        }
+    }
+
+    pub fn player_of_game ( &self ) -> u32 {
+        if let Game9Section::End{player_of_game, ..} = self.sections.last().unwrap() {
+            *player_of_game
+        } else {
+            panic!("last section of game is not end");
+        }
+    }
+
     pub fn field_yardline ( &self, yards: u32 ) -> String {
-        let (showteam, showyards) = if yards > 50 { (0, 50-(yards-50)) } else { (1, yards) };
+        let (showteam, showyards) = if yards > 50 { (0, 100u32.saturating_sub(yards)) } else { (1, yards) };
         format!("{}{:02}", self.team(showteam).short(), showyards)
     }
+
+    /// The score (home, away) after each play, in play order
+    pub fn score_timeline ( &self ) -> Vec<(u32, u32)> {
+        fof9_scoring::score_timeline(self)
+    }
+
+    pub fn scoring_plays ( &self ) -> Vec<ScoringPlay9> {
+        fof9_scoring::scoring_plays(self)
+    }
+
+    /// (home, away), reconstructed from the plays; the game files keep no final score of their own
+    pub fn final_score ( &self ) -> (u32, u32) {
+        fof9_scoring::final_score(self)
+    }
+
+    /// One line per score, for game summaries
+    pub fn scoring_summary ( &self ) -> String {
+        fof9_scoring::scoring_summary(self)
+    }
+
+    /// Checks the reconstructed scoring against the red zone totals of the end of game section
+    pub fn check_score ( &self ) -> Vec<ScoreIssue9> {
+        fof9_scoring::check_score(self)
+    }
+
+    /// The drive chart for a team, 0 = home, 1 = away
+    pub fn drives ( &self, game_team: u32 ) -> Vec<Drive9> {
+        fof9_drive::drives(self, game_team)
+    }
+
+    pub fn drive_summary ( &self, game_team: u32 ) -> DriveSummary9 {
+        fof9_drive::drive_summary(self, game_team)
+    }
+
+    /// End of game totals for a team, 0 = home, 1 = away
+    pub fn team_summary ( &self, game_team: u32 ) -> TeamGameSummary9 {
+        fof9_teamsummary::team_summary(self, game_team)
+    }
+
+    /// Every flag in the game, with whether it was accepted judged from the next play
+    pub fn penalties ( &self ) -> Vec<GamePenalty9> {
+        fof9_penalty::penalties(self)
+    }
+
+    /// Injuries in the game, with names where players are given
+    pub fn injuries ( &self, players: Option<&Players9Data> ) -> Vec<GameInjury9> {
+        fof9_injury::injuries(self, players)
+    }
+
+    pub fn environment ( &self ) -> GameEnvironment9 {
+        fof9_environment::environment(self)
+    }
+
+    /// EPA for every run, pass, field goal and punt in the game, other than two point tries
+    pub fn play_values ( &self, model: &ExpectedPoints9 ) -> Vec<PlayValue9> {
+        fof9_expectedpoints::play_values(self, model)
+    }
+
+    /// Win probability before and after every play; the last play ends at the final result
+    pub fn win_probability ( &self, model: &WinProbability9 ) -> Vec<PlayWinProbability9> {
+        fof9_winprobability::win_probability(self, model)
+    }
+
+    /// (minutes elapsed, home win probability) points for a chart, ending at the final result
+    pub fn win_probability_chart ( &self, model: &WinProbability9 ) -> Vec<(f64, f64)> {
+        fof9_winprobability::win_probability_chart(self, model)
+    }
+}
+
+/// A single play with the game situation before it; teams are 0 = home, 1 = away
+#[derive(Debug, Clone, Copy)]
+pub struct GamePlayInfo9<'a> {
+    pub quarter: u32,
+    pub minutes_remaining: u32,
+    pub seconds_remaining: u32,
+    pub off_team: u32,
+    pub down: u32,
+    pub yards_to_go: u32,
+    pub yardline: u32,
+    pub home_timeouts: u32,
+    pub away_timeouts: u32,
+    pub play: &'a GamePlay9,
+}
+
+impl GamePlayInfo9<'_> {
+    /// plays() only gives an offensive team of 0 or 1
+    pub fn def_team ( &self ) -> u32 {
+        if self.off_team == 0 { 1 } else { 0 }
+    }
+
+    /// Yards the offense has to go to score, with yardline measured from the away goal line as in field_yardline
+    pub fn yards_to_goal ( &self ) -> u32 {
+        if self.off_team == 0 { self.yardline } else { 100u32.saturating_sub(self.yardline) }  // ?
+    }
 }
 
 #[binread]
@@ -150,22 +332,34 @@ impl Display for Game9Section {
 pub enum GamePlay9 {
     #[br(magic = 1u32)] FieldGoal {
         #[br(count = 421)]
-        data: Vec<u32>
+        data: Vec<u32>,
+
+        #[br(calc = KickResult9::from_data(KickType9::FieldGoal, &data))]
+        result: KickResult9,
     },
 
     #[br(magic = 2u32)] Kickoff {
         #[br(count = 421)]
-        data: Vec<u32>
+        data: Vec<u32>,
+
+        #[br(calc = KickResult9::from_data(KickType9::Kickoff, &data))]
+        result: KickResult9,
     },
 
     #[br(magic = 3u32)] OnsideKick {
         #[br(count = 421)]
-        data: Vec<u32>
+        data: Vec<u32>,
+
+        #[br(calc = KickResult9::from_data(KickType9::OnsideKick, &data))]
+        result: KickResult9,
     },
 
     #[br(magic = 4u32)] Punt {
         #[br(count = 421)]
-        data: Vec<u32>
+        data: Vec<u32>,
+
+        #[br(calc = KickResult9::from_data(KickType9::Punt, &data))]
+        result: KickResult9,
     },
 
     #[br(magic = 5u32)] Run {
@@ -196,6 +390,9 @@ pub enum GamePlay9 {
 
         #[br(count = 373)]
         data: Vec<u32>,  // minutes left, seconds left, penalty player (on field?)
+
+        #[br(calc = PlayResult9::from_data(ScrimmagePlay9::Run, &data))]
+        result: PlayResult9,
     },
 
     #[br(magic = 6u32)] Pass {
@@ -225,7 +422,10 @@ pub enum GamePlay9 {
         injury: InjuryInfo9,
 
         #[br(count = 373)]
-        data: Vec<u32>
+        data: Vec<u32>,
+
+        #[br(calc = PlayResult9::from_data(ScrimmagePlay9::Pass, &data))]
+        result: PlayResult9,
     },
 
     #[br(magic = 7u32)] Special {
@@ -245,31 +445,89 @@ pub enum GamePlay9 {
     },
 }
 
-impl Display for GamePlay9 {
-    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+impl GamePlay9 {
+    pub fn kick_result ( &self ) -> Option<&KickResult9> {
         match self {
-            GamePlay9::FieldGoal { .. } => {
-                write!(f, "Field Goal")
-            },
+            GamePlay9::FieldGoal { result, .. } | GamePlay9::Kickoff { result, .. } |
+            GamePlay9::OnsideKick { result, .. } | GamePlay9::Punt { result, .. } => Some(result),
+            _ => None,
+        }
+    }
 
-            GamePlay9::Kickoff { .. } => {
-                write!(f, "Kickoff")
-            },
+    pub fn penalty ( &self ) -> Option<&PenaltyInfo9> {
+        match self {
+            GamePlay9::Run { penalty, .. } | GamePlay9::Pass { penalty, .. } if penalty.is_penalty() => Some(penalty),
+            _ => None,
+        }
+    }
 
-            GamePlay9::OnsideKick { .. } => {
-                write!(f, "Onside Kick")
-            },
+    pub fn extra_point ( &self ) -> Option<&ExtraPointResult9> {
+        match self {
+            GamePlay9::Special { specialplay: SpecialPlay9::ExtraPoint, extra_point, .. } => Some(extra_point),
+            _ => None,
+        }
+    }
+
+    /// Assignment slots of the blitzing defenders, empty for plays other than runs and passes
+    pub fn defensive_blitzers ( &self ) -> &[usize] {
+        match self {
+            GamePlay9::Run { defensive_blitzers, .. } | GamePlay9::Pass { defensive_blitzers, .. } => defensive_blitzers,
+            _ => &[],
+        }
+    }
+
+    pub fn defensive_spies ( &self ) -> &[usize] {
+        match self {
+            GamePlay9::Run { defensive_spies, .. } | GamePlay9::Pass { defensive_spies, .. } => defensive_spies,
+            _ => &[],
+        }
+    }
+
+    pub fn formation ( &self ) -> Option<&FormationData9> {
+        match self {
+            GamePlay9::Run { formation, .. } | GamePlay9::Pass { formation, .. } => Some(formation),
+            _ => None,
+        }
+    }
 
-            GamePlay9::Punt { .. } => {
-                write!(f, "Punt")
+    /// Formations, spy and blitzers, as the play display shows them
+    pub(crate) fn formation_text ( &self ) -> Option<String> {
+        match self {
+            GamePlay9::Run { formation, defensive_blitzers, defensive_spies, .. } |
+            GamePlay9::Pass { formation, defensive_blitzers, defensive_spies, .. } => Some(formation_text(formation, defensive_blitzers, defensive_spies)),
+            _ => None,
+        }
+    }
+
+    pub fn injury ( &self ) -> Option<&InjuryInfo9> {
+        match self {
+            GamePlay9::Run { injury, .. } | GamePlay9::Pass { injury, .. } if injury.is_injury() => Some(injury),
+            _ => None,
+        }
+    }
+
+    pub fn play_result ( &self ) -> Option<&PlayResult9> {
+        match self {
+            GamePlay9::Run { result, .. } | GamePlay9::Pass { result, .. } => Some(result),
+            _ => None,
+        }
+    }
+}
+
+impl Display for GamePlay9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            GamePlay9::FieldGoal { result, .. } | GamePlay9::Kickoff { result, .. } |
+            GamePlay9::OnsideKick { result, .. } | GamePlay9::Punt { result, .. } => {
+                write!(f, "{}", result)
             },
 
-            GamePlay9::Run { formation, defensive_blitzers, defensive_spies, penalty, injury, data, .. } => {
-                write!(f, "{}", display_play_common("Run", formation, defensive_blitzers, defensive_spies, penalty, injury, data))
+            GamePlay9::Run { formation, defensive_blitzers, defensive_spies, penalty, injury, data, result, .. } => {
+                write!(f, "{} => {}", display_play_common("Run", formation, defensive_blitzers, defensive_spies, penalty, injury, data), result)
             },
 
-            GamePlay9::Pass { formation, defensive_blitzers, defensive_spies, penalty, injury, data, .. } => {
-                write!(f, "{}", display_play_common("Pass", formation, defensive_blitzers, defensive_spies, penalty, injury, data))
+            GamePlay9::Pass { formation, defensive_blitzers, defensive_spies, penalty, injury, data, result, .. } => {
+                write!(f, "{} => {}", display_play_common("Pass", formation, defensive_blitzers, defensive_spies, penalty, injury, data), result)
             },
 
             GamePlay9::Special { specialplay, extra_point, .. } => {
@@ -283,8 +541,18 @@ impl Display for GamePlay9 {
     }
 }
 
+#[allow(clippy::ptr_arg)]
 fn display_play_common ( play_type: &str, formation: &FormationData9, defensive_blitzers: &Vec<usize>, defensive_spies: &Vec<usize>, penalty: &PenaltyInfo9, injury: &InjuryInfo9, data: &[u32] ) -> String {
-    format!("{} ({}{}{}{}{}){}", play_type,
+    format!("{} ({}{}{}){}", play_type,
+        formation_text(formation, defensive_blitzers, defensive_spies),
+        penalty,
+        injury,
+        format_args!(" {:?}", &data[0..7]),
+    )
+}
+
+fn formation_text ( formation: &FormationData9, defensive_blitzers: &[usize], defensive_spies: &[usize] ) -> String {
+    format!("{}{}{}",
         formation,
         if defensive_spies.is_empty() { "".to_string() } else {
             format_args!(", {} Spy", formation.blitz_position(*defensive_spies.first().unwrap())).to_string()
@@ -298,9 +566,6 @@ fn display_play_common ( play_type: &str, formation: &FormationData9, defensive_
             } else { first };
             ret
         },
-        penalty,
-        injury,
-        format_args!(" {:?}", &data[0..7]),
     )
 }
 
@@ -316,7 +581,31 @@ pub struct FormationData9 {
 }
 
 impl FormationData9 {
-    fn blitz_position ( &self, blitzer_number: usize ) -> String {
+    pub fn offensive_formation ( &self ) -> &OffensiveFormation9 {
+        &self.offensive_formation
+    }
+
+    pub fn offensive_personnel ( &self ) -> &OffensivePersonnel9 {
+        &self.offensive_personnel
+    }
+
+    pub fn defensive_personnel ( &self ) -> &DefensivePersonnel9 {
+        &self.defensive_personnel
+    }
+
+    pub fn defensive_coverage ( &self ) -> &DefensiveCoverage9 {
+        &self.defensive_coverage
+    }
+
+    pub fn defensive_front ( &self ) -> &DefensiveFront9 {
+        &self.defensive_front
+    }
+
+    pub fn defensive_special ( &self ) -> &SpecialCoverage9 {
+        &self.defensive_special
+    }
+
+    pub(crate) fn blitz_position ( &self, blitzer_number: usize ) -> String {
         use DefensiveFront9::*;
         use DefensivePersonnel9::*;
         use Position::*;
@@ -334,7 +623,7 @@ impl FormationData9 {
 
             _ => None,
         } {
-            format!("{:?}", list[blitzer_number])
+            list[blitzer_number].to_string()
         } else { blitzer_number.to_string() }
     }
 }
@@ -364,7 +653,7 @@ pub struct PenaltyInfo9 {
     loss_of_down: bool,  // verify
 
     #[br(count = 4)]
-    something: Vec<u32>,
+    something: Vec<u32>,  // not decoded
 
     accept_yardline: u32,
     accept_down: u32,
@@ -375,15 +664,55 @@ pub struct PenaltyInfo9 {
     decline_yards_to_go: u32,
 
     #[br(count = 4)]
-    something2: Vec<u32>,
+    something2: Vec<u32>,  // not decoded
 
-    penalty_type: u32,  // (1 = false start, 7 = off pass interference?)
+    penalty_type: u32,  // see PenaltyType9
 }
 
 impl PenaltyInfo9 {
     pub fn is_penalty ( &self ) -> bool {
         self.defensive_penalty || self.offensive_penalty
     }
+
+    pub fn is_offensive ( &self ) -> bool {
+        self.offensive_penalty
+    }
+
+    pub fn is_defensive ( &self ) -> bool {
+        self.defensive_penalty
+    }
+
+    pub fn yards ( &self ) -> u32 {
+        self.penalty_yards
+    }
+
+    pub fn penalty_type ( &self ) -> PenaltyType9 {
+        PenaltyType9::from_code(self.penalty_type)
+    }
+
+    pub fn loss_of_down ( &self ) -> bool {
+        self.loss_of_down
+    }
+
+    /// Down, yards to go and yardline for the next play if the penalty is accepted
+    pub fn accept_situation ( &self ) -> (u32, u32, u32) {
+        (self.accept_down, self.accept_yards_to_go, self.accept_yardline)
+    }
+
+    /// Down, yards to go and yardline for the next play if the penalty is declined
+    pub fn decline_situation ( &self ) -> (u32, u32, u32) {
+        (self.decline_down, self.decline_yards_to_go, self.decline_yardline)
+    }
+
+    /// Whether the penalty was taken, from the situation of the play that followed
+    pub fn outcome ( &self, next_down: u32, next_yards_to_go: u32, next_yardline: u32 ) -> PenaltyOutcome9 {
+        let next = (next_down, next_yards_to_go, next_yardline);
+        match (next == self.accept_situation(), next == self.decline_situation()) {
+            (true, false) => PenaltyOutcome9::Accepted,
+            (false, true) => PenaltyOutcome9::Declined,
+            _ => PenaltyOutcome9::Unknown,
+        }
+    }
 }
 
 impl Display for PenaltyInfo9 {
@@ -396,7 +725,7 @@ impl Display for PenaltyInfo9 {
                     (true, true) => "off/def pen",
                     _ => unreachable!(),
                 },
-                self.penalty_type,
+                self.penalty_type(),
                 self.accept_down,
                 self.accept_yards_to_go,
                 self.accept_yardline,
@@ -409,13 +738,27 @@ impl Display for PenaltyInfo9 {
 
 #[derive(BinRead, Debug)]
 pub struct InjuryInfo9 {
-    injury: u32,
+    injury: u32,  // not decoded
     player: u32,
 }
 
+impl InjuryInfo9 {
+    pub fn is_injury ( &self ) -> bool {
+        self.injury != 0 || self.player != 0
+    }
+
+    pub fn player_id ( &self ) -> Option<u32> {
+        if self.player != 0 { Some(self.player) } else { None }
+    }
+
+    pub fn injury_code ( &self ) -> u32 {
+        self.injury
+    }
+}
+
 impl Display for InjuryInfo9 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
-        if self.injury == 0 && self.player == 0 {
+        if !self.is_injury() {
             write!(f, "")
         } else {
             write!(f, " injury {}/{}", self.player, self.injury)
@@ -533,6 +876,21 @@ pub enum ExtraPointResult9 {
     #[br(magic = b"\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0\0")] Nothing,
 }
 
+impl ExtraPointResult9 {
+    pub fn points ( &self ) -> u32 {
+        match self {
+            ExtraPointResult9::Kick => 1,
+            ExtraPointResult9::TwoPointRun | ExtraPointResult9::TwoPointPass => 2,
+            _ => 0,
+        }
+    }
+
+    pub fn is_kick ( &self ) -> bool {
+        matches!(self, ExtraPointResult9::Kick | ExtraPointResult9::WideLeftKick | ExtraPointResult9::LeftUprightKick |
+            ExtraPointResult9::WideRightKick | ExtraPointResult9::RightUprightKick | ExtraPointResult9::BlockedKick)
+    }
+}
+
 impl Display for ExtraPointResult9 {
     fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
         match self {
@@ -564,6 +922,10 @@ pub struct WeekTeamInfo9 {
 }
 
 impl WeekTeamInfo9 {
+    pub fn number ( &self ) -> u32 {
+        self.number
+    }
+
     pub fn city ( &self ) -> String {
         self.city.to_string()
     }
@@ -579,19 +941,19 @@ impl WeekTeamInfo9 {
 
 #[derive(BinRead, Debug)]
 pub struct DriveInfo9 {
-    start_quarter: u32,
-	start_minutes: u32,
-	start_seconds: u32,
-	end_quarter: u32,
-	end_minutes: u32,
-	end_seconds: u32,
-	start_yards_from_goal: u32,
-	num_plays: u32,
-	yards_gained: i32,
-	result: u32,
+    pub(crate) start_quarter: u32,
+	pub(crate) start_minutes: u32,
+	pub(crate) start_seconds: u32,
+	pub(crate) end_quarter: u32,
+	pub(crate) end_minutes: u32,
+	pub(crate) end_seconds: u32,
+	pub(crate) start_yards_from_goal: u32,
+	pub(crate) num_plays: u32,
+	pub(crate) yards_gained: i32,
+	pub(crate) result: u32,
 }
 
-#[derive(BinRead, Debug)]
+#[derive(BinRead, Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct PassStats9 {
     screen: PassPlayStats9,
     short: PassPlayStats9,
@@ -600,14 +962,71 @@ pub struct PassStats9 {
     other: PassPlayStats9,
 }
 
-#[derive(BinRead, Debug)]
+impl PassStats9 {
+    pub fn depth ( &self, depth: PassDepth9 ) -> PassPlayStats9 {
+        match depth {
+            PassDepth9::Screen => self.screen,
+            PassDepth9::Short => self.short,
+            PassDepth9::Medium => self.medium,
+            PassDepth9::Long => self.long,
+            PassDepth9::Other => self.other,
+        }
+    }
+
+    pub fn total ( &self ) -> PassPlayStats9 {
+        let mut total = PassPlayStats9::default();
+        for stats in [&self.screen, &self.short, &self.medium, &self.long, &self.other] {
+            total.add(stats);
+        }
+        total
+    }
+
+    pub fn add ( &mut self, other: &PassStats9 ) {
+        self.screen.add(&other.screen);
+        self.short.add(&other.short);
+        self.medium.add(&other.medium);
+        self.long.add(&other.long);
+        self.other.add(&other.other);
+    }
+}
+
+#[derive(BinRead, Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct PassPlayStats9 {
     attempts: u32,
     completions: u32,
     yards: i32,
 }
 
-#[derive(BinRead, Debug)]
+impl PassPlayStats9 {
+    pub fn attempts ( &self ) -> u32 {
+        self.attempts
+    }
+
+    pub fn completions ( &self ) -> u32 {
+        self.completions
+    }
+
+    pub fn yards ( &self ) -> i32 {
+        self.yards
+    }
+
+    /// Percent, 0 when there were no attempts
+    pub fn completion_pct ( &self ) -> f64 {
+        if self.attempts == 0 { 0.0 } else { 100.0 * f64::from(self.completions) / f64::from(self.attempts) }
+    }
+
+    pub fn yards_per_attempt ( &self ) -> f64 {
+        if self.attempts == 0 { 0.0 } else { f64::from(self.yards) / f64::from(self.attempts) }
+    }
+
+    pub fn add ( &mut self, other: &PassPlayStats9 ) {
+        self.attempts += other.attempts;
+        self.completions += other.completions;
+        self.yards += other.yards;
+    }
+}
+
+#[derive(BinRead, Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct RunStats9 {
     left: RunPlayStats9,
     middle: RunPlayStats9,
@@ -615,13 +1034,58 @@ pub struct RunStats9 {
     none: RunPlayStats9,
 }
 
-#[derive(BinRead, Debug)]
+impl RunStats9 {
+    pub fn direction ( &self, direction: RunDirection9 ) -> RunPlayStats9 {
+        match direction {
+            RunDirection9::Left => self.left,
+            RunDirection9::Middle => self.middle,
+            RunDirection9::Right => self.right,
+            RunDirection9::None => self.none,
+        }
+    }
+
+    pub fn total ( &self ) -> RunPlayStats9 {
+        let mut total = RunPlayStats9::default();
+        for stats in [&self.left, &self.middle, &self.right, &self.none] {
+            total.add(stats);
+        }
+        total
+    }
+
+    pub fn add ( &mut self, other: &RunStats9 ) {
+        self.left.add(&other.left);
+        self.middle.add(&other.middle);
+        self.right.add(&other.right);
+        self.none.add(&other.none);
+    }
+}
+
+#[derive(BinRead, Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct RunPlayStats9 {
     attempts: u32,
     yards: i32
 }
 
-#[derive(BinRead, Debug)]
+impl RunPlayStats9 {
+    pub fn attempts ( &self ) -> u32 {
+        self.attempts
+    }
+
+    pub fn yards ( &self ) -> i32 {
+        self.yards
+    }
+
+    pub fn yards_per_carry ( &self ) -> f64 {
+        if self.attempts == 0 { 0.0 } else { f64::from(self.yards) / f64::from(self.attempts) }
+    }
+
+    pub fn add ( &mut self, other: &RunPlayStats9 ) {
+        self.attempts += other.attempts;
+        self.yards += other.yards;
+    }
+}
+
+#[derive(BinRead, Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct PossessionStats9 {
     seconds: u32,
     red_zone_attempts: u32,
@@ -629,12 +1093,63 @@ pub struct PossessionStats9 {
     red_zone_fg: u32,
 }
 
-#[derive(BinRead, Debug)]
+impl PossessionStats9 {
+    /// Time of possession
+    pub fn seconds ( &self ) -> u32 {
+        self.seconds
+    }
+
+    pub fn red_zone_attempts ( &self ) -> u32 {
+        self.red_zone_attempts
+    }
+
+    pub fn red_zone_touchdowns ( &self ) -> u32 {
+        self.red_zone_td
+    }
+
+    pub fn red_zone_field_goals ( &self ) -> u32 {
+        self.red_zone_fg
+    }
+
+    /// Percent of red zone trips ending in a touchdown
+    pub fn red_zone_td_pct ( &self ) -> f64 {
+        if self.red_zone_attempts == 0 { 0.0 } else { 100.0 * f64::from(self.red_zone_td) / f64::from(self.red_zone_attempts) }
+    }
+
+    pub fn add ( &mut self, other: &PossessionStats9 ) {
+        self.seconds += other.seconds;
+        self.red_zone_attempts += other.red_zone_attempts;
+        self.red_zone_td += other.red_zone_td;
+        self.red_zone_fg += other.red_zone_fg;
+    }
+}
+
+#[derive(BinRead, Debug, Clone, Copy, Default, PartialEq, Eq)]
 pub struct Attendance9 {
     attendance: u32,
     capacity: u32,
 }
 
+impl Attendance9 {
+    pub fn attendance ( &self ) -> u32 {
+        self.attendance
+    }
+
+    pub fn capacity ( &self ) -> u32 {
+        self.capacity
+    }
+
+    /// Percent of seats sold, 0 for a section with no seats
+    pub fn sell_through ( &self ) -> f64 {
+        if self.capacity == 0 { 0.0 } else { 100.0 * f64::from(self.attendance) / f64::from(self.capacity) }
+    }
+
+    pub fn add ( &mut self, other: &Attendance9 ) {
+        self.attendance += other.attendance;
+        self.capacity += other.capacity;
+    }
+}
+
 #[derive(BinRead, Debug)]
 pub enum OffensivePersonnel9 {
 	#[br(magic = 0u32)] Op005,
@@ -0,0 +1,210 @@
+use std::{fmt::{Display, Write}, fs, path::Path};
+
+use crate::{fof9_drive::GameClock9, fof9_scoring::tries, fof9_weekdata::{Game9Data, GamePlayInfo9, Week9Data}};
+
+const FEATURES: usize = 8;
+const REGULATION_SECONDS: u32 = 4 * 15 * 60;
+const FIT_ITERATIONS: usize = 500;
+const LEARNING_RATE: f64 = 0.5;
+const CSV_HEADER: &str = "feature,weight";
+const FEATURE_NAMES: [&str; FEATURES] = ["bias", "score", "score_late", "time_left", "field", "down", "distance", "timeouts"];
+
+
+/// Logistic model of the offense winning, fit on a league's completed games
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct WinProbability9 {
+    pub weights: [f64; FEATURES],
+}
+
+impl Default for WinProbability9 {
+    /// Unfit: every team at 50%
+    fn default ( ) -> Self {
+        WinProbability9 { weights: [0.0; FEATURES] }
+    }
+}
+
+impl WinProbability9 {
+    /// Batch gradient descent on the log loss over every play of the completed games other than two point tries;
+    /// ties count as half a win
+    pub fn fit ( weeks: &[Week9Data] ) -> Self {
+        let mut samples: Vec<([f64; FEATURES], f64)> = Vec::new();
+        for game in weeks.iter().flat_map(|week| week.games.iter()).filter(|game| game.plays().last().is_some_and(|last| last.quarter >= 4)) {
+            let home_result = home_result(game);
+            let plays: Vec<_> = game.plays().collect();
+            for ((info, score), is_try) in plays.iter().zip(scores_before(game)).zip(tries(&plays)) {
+                if is_try {
+                    continue;
+                }
+                let result = if info.off_team == 0 { home_result } else { 1.0 - home_result };
+                samples.push((features(info, score), result));
+            }
+        }
+
+        let mut model = WinProbability9::default();
+        if samples.is_empty() {
+            return model;
+        }
+        let count = samples.len() as f64;
+        for _ in 0..FIT_ITERATIONS {
+            let mut gradient = [0.0; FEATURES];
+            for (x, y) in &samples {
+                let error = model.predict(x) - y;
+                for (g, value) in gradient.iter_mut().zip(x) {
+                    *g += error * value;
+                }
+            }
+            for (w, g) in model.weights.iter_mut().zip(gradient) {
+                *w -= LEARNING_RATE * g / count;
+            }
+        }
+        model
+    }
+
+    fn predict ( &self, x: &[f64; FEATURES] ) -> f64 {
+        let z: f64 = self.weights.iter().zip(x).map(|(w, value)| w * value).sum();
+        1.0 / (1.0 + (-z).exp())
+    }
+
+    /// Chance the team with the ball wins, given the score (home, away) before the play
+    pub fn probability ( &self, info: &GamePlayInfo9, score: (u32, u32) ) -> f64 {
+        self.predict(&features(info, score))
+    }
+
+    pub fn to_csv ( &self ) -> String {
+        let mut out = String::from(CSV_HEADER);
+        out.push('\n');
+        for (name, weight) in FEATURE_NAMES.iter().zip(self.weights) {
+            let _ = writeln!(out, "{},{}", name, weight);
+        }
+        out
+    }
+
+    pub fn from_csv ( csv: &str ) -> Option<Self> {
+        let mut lines = csv.lines();
+        if lines.next()?.trim() != CSV_HEADER {
+            return None;
+        }
+
+        let mut model = WinProbability9::default();
+        for line in lines.filter(|line| !line.trim().is_empty()) {
+            let (name, weight) = line.split_once(',')?;
+            let index = FEATURE_NAMES.iter().position(|&feature| feature == name.trim())?;
+            model.weights[index] = weight.trim().parse().ok()?;
+        }
+        Some(model)
+    }
+
+    /// Saves the fitted model, so a league only has to be fit once
+    pub fn save<P: AsRef<Path>> ( &self, path: P ) -> std::io::Result<()> {
+        fs::write(path, self.to_csv())
+    }
+
+    pub fn load<P: AsRef<Path>> ( path: P ) -> Option<Self> {
+        Self::from_csv(&fs::read_to_string(path).ok()?)
+    }
+}
+
+impl Display for WinProbability9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        for (name, weight) in FEATURE_NAMES.iter().zip(self.weights) {
+            writeln!(f, "{:<12}{:>9.4}", name, weight)?;
+        }
+        Ok(())
+    }
+}
+
+/// Score differential, time left, field position, down, distance and timeouts, all from the offense's side and scaled near -1..1
+fn features ( info: &GamePlayInfo9, score: (u32, u32) ) -> [f64; FEATURES] {
+    let (own, other) = if info.off_team == 0 { score } else { (score.1, score.0) };
+    let (own_timeouts, other_timeouts) = if info.off_team == 0 { (info.home_timeouts, info.away_timeouts) } else { (info.away_timeouts, info.home_timeouts) };
+    let elapsed = GameClock9 { quarter: info.quarter, minutes: info.minutes_remaining, seconds: info.seconds_remaining }.elapsed();
+    let time_left = f64::from(REGULATION_SECONDS.saturating_sub(elapsed)) / f64::from(REGULATION_SECONDS);
+    let difference = (f64::from(own) - f64::from(other)) / 7.0;
+
+    [
+        1.0,
+        difference,
+        // a lead matters more as time runs out
+        difference / (time_left + 0.05).sqrt(),
+        time_left,
+        (50.0 - f64::from(info.yards_to_goal().min(100))) / 50.0,
+        f64::from(info.down.clamp(1, 4) - 1) / 3.0,
+        f64::from(info.yards_to_go.min(20)) / 20.0,
+        (f64::from(own_timeouts) - f64::from(other_timeouts)) / 3.0,
+    ]
+}
+
+/// 1 for a home win, 0 for a loss, half for a tie; from the plays, as the game files keep no final score
+fn home_result ( game: &Game9Data ) -> f64 {
+    let (home, away) = game.final_score();
+    match home.cmp(&away) {
+        std::cmp::Ordering::Greater => 1.0,
+        std::cmp::Ordering::Less => 0.0,
+        std::cmp::Ordering::Equal => 0.5,
+    }
+}
+
+/// Score (home, away) before each play
+fn scores_before ( game: &Game9Data ) -> Vec<(u32, u32)> {
+    let timeline = game.score_timeline();
+    std::iter::once((0, 0)).chain(timeline.iter().copied()).take(timeline.len()).collect()
+}
+
+/// Home win probability around one play
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct PlayWinProbability9 {
+    pub play_index: usize,  // index into Game9Data::plays()
+    pub off_team: u32,
+    pub minutes_elapsed: f64,
+    pub home_before: f64,
+    pub home_after: f64,
+}
+
+impl PlayWinProbability9 {
+    /// Win probability added for the team with the ball
+    pub fn wpa ( &self ) -> f64 {
+        let home = self.home_after - self.home_before;
+        if self.off_team == 0 { home } else { -home }
+    }
+
+    pub fn offense_before ( &self ) -> f64 {
+        if self.off_team == 0 { self.home_before } else { 1.0 - self.home_before }
+    }
+}
+
+impl Display for PlayWinProbability9 {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{:>5.1} min home {:>5.1}% -> {:>5.1}% ({:+.1}% for offense)", self.minutes_elapsed,
+            100.0 * self.home_before, 100.0 * self.home_after, 100.0 * self.wpa())
+    }
+}
+
+pub(crate) fn win_probability ( game: &Game9Data, model: &WinProbability9 ) -> Vec<PlayWinProbability9> {
+    let plays: Vec<_> = game.plays().collect();
+    let home_before: Vec<f64> = plays.iter().zip(scores_before(game)).map(|(info, score)| {
+        let offense = model.probability(info, score);
+        if info.off_team == 0 { offense } else { 1.0 - offense }
+    }).collect();
+
+    let final_result = home_result(game);
+
+    plays.iter().enumerate().map(|(play_index, info)| {
+        let elapsed = GameClock9 { quarter: info.quarter, minutes: info.minutes_remaining, seconds: info.seconds_remaining }.elapsed();
+        PlayWinProbability9 {
+            play_index,
+            off_team: info.off_team,
+            minutes_elapsed: f64::from(elapsed) / 60.0,
+            home_before: home_before[play_index],
+            home_after: home_before.get(play_index + 1).copied().unwrap_or(final_result),
+        }
+    }).collect()
+}
+
+pub(crate) fn win_probability_chart ( game: &Game9Data, model: &WinProbability9 ) -> Vec<(f64, f64)> {
+    let series = game.win_probability(model);
+    let mut chart: Vec<(f64, f64)> = series.iter().map(|play| (play.minutes_elapsed, play.home_before)).collect();
+    if let Some(last) = series.last() {
+        chart.push((last.minutes_elapsed, last.home_after));
+    }
+    chart
+}
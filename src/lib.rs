@@ -12,9 +12,53 @@ mod fof9_utility;
 mod fof9_leaguedata;
 mod fof9_weekdata;
 mod fof9_playerdata;
+mod fof9_reference;
+mod fof9_playerquery;
+mod fof9_playercard;
+mod fof9_playerdiff;
+mod fof9_position;
+mod fof9_validate;
+mod fof9_playresult;
+mod fof9_boxscore;
+mod fof9_scoring;
+mod fof9_drive;
+mod fof9_teamsummary;
+mod fof9_playbyplay;
+mod fof9_penalty;
+mod fof9_injury;
+mod fof9_environment;
+mod fof9_scouting;
+mod fof9_blitz;
+mod fof9_expectedpoints;
+mod fof9_winprobability;
+#[cfg(test)]
+mod fof9_testdata;
 pub use fof9_leaguedata::League9Data;
-pub use fof9_weekdata::{Week9Data, Game9Section, GamePlay9, Game9Data};
-pub use fof9_playerdata::{AltPlayers9Header, AltPlayer9Data, AltPlayer9Id, Players9Data, Player9Data, PlayerPositionGroup9};
+pub use fof9_weekdata::{Week9Data, Game9Section, GamePlay9, Game9Data, GamePlayInfo9, WeekTeamInfo9, Attendance9, PassStats9, PassPlayStats9, RunStats9, RunPlayStats9, PossessionStats9, FormationData9, OffensiveFormation9, OffensivePersonnel9, DefensivePersonnel9, DefensiveCoverage9, DefensiveFront9, SpecialCoverage9};
+pub use fof9_teamsummary::TeamGameSummary9;
+pub use fof9_playbyplay::{PlayByPlay9, PlayByPlayStyle9};
+pub use fof9_expectedpoints::{ExpectedPoints9, ExpectedPointsCell9, PlayValue9};
+pub use fof9_winprobability::{WinProbability9, PlayWinProbability9};
+pub use fof9_blitz::{PlayOutcomes9, BlitzAnalysis9};
+pub use fof9_scouting::{Situation9, TendencyTable9, ScoutingReport9};
+pub use fof9_environment::{Weather9, SeatingSection9, GameLocation9, GameTime9, GameEnvironment9, StadiumAttendance9, AttendanceReport9};
+pub use fof9_injury::{GameInjury9, InjuryReport9};
+pub use fof9_penalty::{PenaltyType9, PenaltyOutcome9, GamePenalty9, PenaltyTotals9, PenaltyReport9};
+pub use fof9_drive::{Drive9, DriveResult9, DriveSummary9, GameClock9};
+pub use fof9_scoring::{ScoreType9, ScoringPlay9, ScoreIssue9};
+pub use fof9_boxscore::{BoxScore9, TeamBox9, PlayerLines9, PassingLine9, RushingLine9, ReceivingLine9, DefensiveLine9, KickingLine9};
+pub use fof9_playercard::{PlayerCard9, CardFormat9};
+pub use fof9_playresult::{KickResult9, KickType9, FieldGoalResult9, PlayResult9, ScrimmagePlay9, RunDirection9, PassDepth9};
+pub use fof9_validate::{RosterValidator9, RosterIssue9};
+pub use fof9_position::{Position, PositionUnit, ParsePositionError};
+pub use fof9_playerdiff::{PlayersDiff9, PlayerDiff9, PlayerChange9};
+pub use fof9_playerdata::{AltPlayers9Header, AltPlayer9Data, AltPlayer9Id, Players9Data, Player9Data, PlayerPosition9, PlayerPositionGroup9, RelativeStats9,
+    StaffData9, StaffCareerEntry9,
+    DraftInfo9, PlayerEvent9, PlayerTimelineItem9, PlayerTimelineEntry9,
+    NextData9, MoreData9, CombineResults9, DraftProspect9, TransactionType9, PendingTransaction9};
+pub use fof9_utility::{Date, LengthInches};
+pub use fof9_playerquery::{PlayerQuery9, PlayerSort9};
+pub use fof9_reference::{ReferenceTables9, CityInfo9};
 
 pub const LEAGUES_9_PATH: &str = "Solecismic Software\\Front Office Football Nine\\saved_games";
 pub const LEAGUEINFO_9_FILENAME: &str = "league.dat";
@@ -146,6 +190,50 @@ impl League9FileInfo {
         }
     }
 
+    /// Every week of a year that loads, in week order
+    pub fn get_season ( &self, year: u16 ) -> Vec<Week9Data> {
+        self.get_weeks_list_for_year(year).unwrap_or_default().into_iter()
+            .filter_map(|week| self.get_week(year, week))
+            .collect()
+    }
+
+    pub fn injury_report ( &self, year: u16 ) -> Option<InjuryReport9> {
+        let weeks = self.get_season(year);
+        if weeks.is_empty() {
+            None
+        } else {
+            Some(InjuryReport9::new(&weeks, self.get_players().as_ref()))
+        }
+    }
+
+    pub fn attendance_report ( &self, year: u16 ) -> Option<AttendanceReport9> {
+        let weeks = self.get_season(year);
+        if weeks.is_empty() {
+            None
+        } else {
+            Some(AttendanceReport9::new(&weeks))
+        }
+    }
+
+    /// Expected points fit on every year of the league, since each league has its own engine tuning
+    pub fn fit_expected_points ( &self ) -> ExpectedPoints9 {
+        let mut model = ExpectedPoints9::default();
+        for year in self.get_years_list_reversed() {
+            for week in self.get_season(year) {
+                for game in week.games.iter() {
+                    model.add_game(game);
+                }
+            }
+        }
+        model
+    }
+
+    /// Fit on every completed game in every year
+    pub fn fit_win_probability ( &self ) -> WinProbability9 {
+        let weeks: Vec<Week9Data> = self.get_years_list_reversed().into_iter().flat_map(|year| self.get_season(year)).collect();
+        WinProbability9::fit(&weeks)
+    }
+
     pub fn get_players ( &self ) -> Option<Players9Data> {
         let mut file = self.get_players_file();
 
@@ -286,45 +374,6 @@ impl Leagues9List {
     }
 }
 
-#[derive(Debug)]
-pub enum Position {
-    QB,
-    RB,
-    FB,
-    TE,
-    FL,
-    SE,
-    WR,
-    C,
-    RG,
-    LG,
-    RT,
-    LT,
-    P,
-    K,
-    DLE,
-    DRE,
-    DLT,
-    DRT,
-    DT,
-    UT,
-    NT,
-    SILB,
-    WILB,
-    MLB,
-    SLB,
-    WLB,
-    RCB,
-    LCB,
-    NB,
-    DB,
-    SS,
-    FS,
-    LS,
-    PR,
-    KR,
-}
-
 #[cfg(test)]
 mod tests {
     use crate::LeagueInfo;
@@ -63,3 +63,36 @@ fn league_9_load() {
 
     info!("Done");
 }
+
+#[test]
+fn validate_rosters() {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    for league_name in league_info.league_name_list() {
+        info!("processing league: {}", league_name);
+        if let Some(mut league_file_info) = league_info.get_league_info(&league_name) {
+            league_file_info.load_data();
+            match (league_file_info.data(), league_file_info.get_players()) {
+                (Some(league_data), Some(players)) => {
+                    let issues = fofdata::RosterValidator9::new().validate(&players, league_data);
+                    debug!("{} roster issues", issues.len());
+                    for issue in issues {
+                        debug!("{}", issue);
+                    }
+                },
+
+                _ => {
+                    error!("unable to load league and players for {}", league_name);
+                    done = false;
+                }
+            }
+        } else { error!("unable to find league file info for {}", league_name); }
+    }
+
+    assert!(done);
+}
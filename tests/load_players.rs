@@ -1,4 +1,4 @@
-use fofdata::{AltPlayer9Data, AltPlayer9Id, AltPlayers9Header, LeagueInfo};
+use fofdata::{AltPlayer9Data, AltPlayer9Id, AltPlayers9Header, LeagueInfo, PlayerEvent9, PlayerPositionGroup9, PlayerSort9, PlayerTimelineItem9};
 use log::{debug, error, info};
 use binrw::BinReaderExt;
 
@@ -115,8 +115,11 @@ fn load_players ( ) {
                 debug!("{}", player);
             }
             debug!("number staff: {}", players.staff().len());
-            for _staff in players.staff() {
-                // debug!("{}", staff);  // TODO
+            for staff in players.staff() {
+                debug!("{}", staff);
+                for entry in staff.career() {
+                    debug!("    {}", entry);
+                }
             }
         } else {
             error!("unable to read players for league {}", LEAGUE_NAME);
@@ -154,7 +157,25 @@ fn load_team_players ( ) {
 
             for player in players.players().iter().filter(|p| { if let Some(t) = p.team_id() { t == team } else { false } }) {
                 debug!("{}", player);
+                for entry in player.timeline() {
+                    debug!("    {}", entry);
+                }
+            }
+
+            // a drafted player's history should start with the draft, by the team that drafted him
+            let (mut drafted, mut agree) = (0, 0);
+            for player in players.players() {
+                let Some(draft) = player.draft() else { continue };
+                let first = player.timeline().into_iter().find(|entry| matches!(entry.item, PlayerTimelineItem9::Event { .. }));
+                let Some(first) = first else { continue };
+                drafted += 1;
+                if matches!(first.item, PlayerTimelineItem9::Event { event: Some(PlayerEvent9::Drafted), .. }) && first.team == Some(draft.team) {
+                    agree += 1;
+                } else {
+                    debug!("{}: drafted {}, first event {}", player.name(), draft, first);
+                }
             }
+            assert!(agree * 10 >= drafted * 9, "{} of {} drafted players start with the draft", agree, drafted);
 
             debug!("number staff: {}", players.staff().len());
             // for _staff in players.staff() {
@@ -207,3 +228,172 @@ fn load_all_players ( ) {
     assert!(done);
 }
 
+
+#[test]
+fn players_by_college ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        if let Some(players) = league.get_players() {
+            let tables = fofdata::ReferenceTables9::from_csv("id,city,state\n1,Canton,OH\n", "id,college\n1,Ohio State\n");
+            assert_eq!(tables.city(1).map(|city| city.state.as_str()), Some("OH"));
+            assert_eq!(tables.college(1), Some("Ohio State"));
+
+            let by_college = players.players_by_college(&tables);
+            for (college, college_players) in &by_college {
+                debug!("{}: {} players", college, college_players.len());
+            }
+            assert_eq!(by_college.values().map(Vec::len).sum::<usize>(), players.players().len());
+
+            let by_state = players.players_by_state(&tables);
+            for (state, state_players) in &by_state {
+                debug!("{}: {} players", state, state_players.len());
+            }
+            assert_eq!(by_state.values().map(Vec::len).sum::<usize>(), players.players().len());
+        } else {
+            error!("unable to read players for league {}", LEAGUE_NAME);
+            done = false;
+        }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
+    }
+
+    assert!(done);
+}
+
+#[test]
+fn load_draft_pools ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(mut league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        league.load_league_data();
+
+        if let (Some(players), Some(number_teams)) = (league.get_players(), league.data().map(|data| data.number_teams as usize)) {
+            for (list, prospects) in players.prospect_lists().iter().enumerate() {
+                debug!("prospect list {}: {} entries", list + 1, prospects.len());
+            }
+            let prospects = players.draft_prospects();
+            for prospect in prospects.iter() {
+                debug!("{}", prospect);
+            }
+            // checks on the guessed layouts: ids have to resolve, times and teams have to be plausible
+            let resolved = prospects.iter().filter(|prospect| prospect.player.is_some()).count();
+            assert!(resolved * 10 >= prospects.len() * 9, "{} of {} prospects are players", resolved, prospects.len());
+            let timed: Vec<u32> = prospects.iter().map(|prospect| prospect.prospect.combine().dash).filter(|&dash| dash != 0).collect();
+            let sane = timed.iter().filter(|dash| (400..=600).contains(*dash)).count();
+            assert!(sane * 10 >= timed.len() * 9, "{} of {} dash times between 4 and 6 seconds", sane, timed.len());
+
+            let transactions = players.pending_transactions();
+            for transaction in transactions.iter() {
+                debug!("{}", transaction);
+                assert!(transaction.transaction.team_id().is_none_or(|team| team < number_teams), "{}", transaction);
+            }
+            let resolved = transactions.iter().filter(|transaction| transaction.player.is_some()).count();
+            assert!(resolved * 10 >= transactions.len() * 9, "{} of {} transactions are for players", resolved, transactions.len());
+            debug!("number free agents: {}", players.free_agents().count());
+        } else {
+            error!("unable to read players or league data for league {}", LEAGUE_NAME);
+            done = false;
+        }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
+    }
+
+    assert!(done);
+}
+
+#[test]
+fn query_players ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        if let Some(players) = league.get_players() {
+            let quarterbacks = players.query()
+                .position_group(PlayerPositionGroup9::QB)
+                .experience(1..)
+                .sort_by_descending(PlayerSort9::Experience)
+                .results();
+            debug!("number veteran quarterbacks: {}", quarterbacks.len());
+            assert!(quarterbacks.windows(2).all(|pair| pair[0].years_experience() >= pair[1].years_experience()));
+            if let Some(best) = quarterbacks.first() {
+                debug!("{}", fofdata::PlayerCard9::new(best).to_text());
+                debug!("{}", fofdata::PlayerCard9::new(best).to_markdown());
+            }
+            for player in quarterbacks {
+                debug!("{}", player);
+                if players.player_data(player.player_id()).is_none() {
+                    error!("player {} missing from index", player.player_id());
+                    done = false;
+                }
+            }
+
+            let free_agent_rookies = players.query().free_agents().experience(..1).count();
+            debug!("number free agent rookies: {}", free_agent_rookies);
+        } else {
+            error!("unable to read players for league {}", LEAGUE_NAME);
+            done = false;
+        }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
+    }
+
+    assert!(done);
+}
+
+#[test]
+fn diff_players ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        if let (Some(before), Some(after)) = (league.get_players(), league.get_players()) {
+            let diff = fofdata::PlayersDiff9::new(&before, &after);
+            if !diff.is_empty() {
+                error!("players differ from themselves:\n{}", diff.changelog());
+                done = false;
+            }
+        } else {
+            error!("unable to read players for league {}", LEAGUE_NAME);
+            done = false;
+        }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
+    }
+
+    assert!(done);
+}
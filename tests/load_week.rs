@@ -1,4 +1,5 @@
-use fofdata::{LeagueInfo, Game9Section, Game9Data,};
+use fofdata::{LeagueInfo, Game9Data, BoxScore9, TeamGameSummary9, PlayByPlay9, PlayByPlayStyle9, PenaltyReport9, ScoutingReport9, BlitzAnalysis9, ExpectedPoints9, WinProbability9, KickType9, PlayerPosition9, DriveResult9, GameClock9, GamePlay9,};
+use std::collections::BTreeMap;
 use log::{info, debug, error};
 
 mod common;
@@ -97,19 +98,451 @@ fn load_all_weeks ( ) {
     assert!(done);
 }
 
-fn show_game ( game: &Game9Data ) {
-    for section in game.sections.iter() {
-        match section {
-            Game9Section::Start {..} => {
-                debug!("starting game: {}", section);
-            },
-            Game9Section::Play {quarter, minutes_remaining, seconds_remaining, down, yards_to_go, yardline, off_team, play, ..} => {
-                debug!("{}-{}-{} ({}Q: {}:{:02}), {} {}", down, yards_to_go, game.field_yardline(*yardline), quarter, minutes_remaining, seconds_remaining, game.team(*off_team).short(), play);
-            },
-            Game9Section::End {..} => {
-                debug!("ending game");
-            },
+#[test]
+fn kick_results ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+    const YEAR_SELECTION: usize = 0;
+    const WEEK: u8 = 1;
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(mut league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        league.load_league_data();
+
+        if let (Some(year), Some(players)) = (league.get_year(YEAR_SELECTION), league.get_players()) {
+            if let Some(week) = league.get_week(year, WEEK) {
+                let (mut field_goals, mut by_kickers) = (0, 0);
+                for game in week.games.iter() {
+                    for info in game.plays() {
+                        let Some(kick) = info.play.kick_result() else { continue };
+                        debug!("{}", kick);
+                        let kicker = players.player_data(kick.kicker());
+                        assert!(kicker.is_some(), "kicker {} is not a player", kick.kicker());
+                        if kick.kick_type() == KickType9::FieldGoal {
+                            field_goals += 1;
+                            if kicker.is_some_and(|kicker| kicker.position() == PlayerPosition9::K) { by_kickers += 1; }
+                        }
+                    }
+                }
+                // backups kick now and then, but most field goals have to come from kickers if the layout is right
+                assert!(by_kickers * 2 > field_goals, "{} of {} field goals by kickers", by_kickers, field_goals);
+            } else {
+                error!("unable to load week {} for year 0 ({}) in league {}", WEEK, year, LEAGUE_NAME);
+                done = false;
+            }
+        } else {
+            error!("unable to find year 0 or players in league {}", LEAGUE_NAME);
+            done = false;
+        }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
+    }
+
+    assert!(done);
+}
+
+#[test]
+fn play_results ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+    const YEAR_SELECTION: usize = 0;
+    const WEEK: u8 = 1;
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(mut league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        league.load_league_data();
+
+        if let (Some(year), Some(players)) = (league.get_year(YEAR_SELECTION), league.get_players()) {
+            if let Some(week) = league.get_week(year, WEEK) {
+                let (mut passes, mut by_quarterbacks) = (0, 0);
+                for game in week.games.iter() {
+                    for info in game.plays() {
+                        let Some(result) = info.play.play_result() else { continue };
+                        debug!("{}", info.play);
+                        assert!(result.minutes_after() <= 15 && result.seconds_after() < 60, "clock {}:{:02}", result.minutes_after(), result.seconds_after());
+                        for player_id in [result.ball_carrier(), result.passer(), result.target(), result.tackler()].into_iter().flatten() {
+                            assert!(players.player_data(player_id).is_some(), "player {} in {} is not a player", player_id, result);
+                        }
+                        if let Some(passer) = result.passer() {
+                            passes += 1;
+                            if players.player_data(passer).is_some_and(|passer| passer.position() == PlayerPosition9::QB) { by_quarterbacks += 1; }
+                        }
+                    }
+                }
+                assert!(by_quarterbacks * 2 > passes, "{} of {} passes by quarterbacks", by_quarterbacks, passes);
+            } else {
+                error!("unable to load week {} for year 0 ({}) in league {}", WEEK, year, LEAGUE_NAME);
+                done = false;
+            }
+        } else {
+            error!("unable to find year 0 or players in league {}", LEAGUE_NAME);
+            done = false;
+        }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
+    }
+
+    assert!(done);
+}
+
+#[test]
+fn drive_results ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+    const YEAR_SELECTION: usize = 0;
+    const WEEK: u8 = 1;
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(mut league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        league.load_league_data();
+
+        if let Some(year) = league.get_year(YEAR_SELECTION) {
+            if let Some(week) = league.get_week(year, WEEK) {
+                // result => (drives checked, drives whose last play agrees)
+                let mut checks = BTreeMap::<String, (u32, u32)>::new();
+                for game in week.games.iter() {
+                    let plays: Vec<_> = game.plays().collect();
+                    for team in 0..2 {
+                        for drive in game.drives(team) {
+                            let Some(result) = drive.result else { continue };
+                            let last = plays.iter().rev().find(|info| {
+                                let elapsed = GameClock9 { quarter: info.quarter, minutes: info.minutes_remaining, seconds: info.seconds_remaining }.elapsed();
+                                info.off_team == team && (drive.start.elapsed()..=drive.end.elapsed()).contains(&elapsed)
+                                    && matches!(info.play, GamePlay9::Run { .. } | GamePlay9::Pass { .. } | GamePlay9::FieldGoal { .. } | GamePlay9::Punt { .. })
+                            });
+                            let Some(last) = last else { continue };
+
+                            let (play, kick) = (last.play.play_result(), last.play.kick_result());
+                            let field_goal = kick.and_then(|kick| kick.field_goal());
+                            let agrees = match result {
+                                DriveResult9::Touchdown => play.is_some_and(|play| play.touchdown() && !play.is_turnover()),
+                                DriveResult9::FieldGoal => field_goal.is_some_and(|fg| fg.is_good()),
+                                DriveResult9::MissedFieldGoal => field_goal.is_some_and(|fg| !fg.is_good()),
+                                DriveResult9::Punt => kick.is_some_and(|kick| kick.kick_type() == KickType9::Punt),
+                                DriveResult9::Fumble => play.is_some_and(|play| play.fumble_lost()),
+                                DriveResult9::Interception => play.is_some_and(|play| play.interception()),
+                                DriveResult9::Downs => play.is_some_and(|play| !play.first_down() && !play.touchdown()),
+                                DriveResult9::Safety => play.is_some_and(|play| play.safety()),
+                                // the clock ends these, not the play
+                                DriveResult9::EndOfHalf | DriveResult9::EndOfGame => continue,
+                            };
+                            if !agrees {
+                                debug!("{} {} ended by {}", game.team(team).short(), drive, last.play);
+                            }
+                            let check = checks.entry(result.to_string()).or_default();
+                            check.0 += 1;
+                            if agrees { check.1 += 1; }
+                        }
+                    }
+                }
+                // the odd drive ends on a penalty, but a wrong code would disagree with nearly all of its drives
+                for (result, (checked, agreed)) in checks {
+                    info!("{}: {} of {} drives agree with their last play", result, agreed, checked);
+                    assert!(agreed * 10 >= checked * 9, "{}: {} of {} drives agree with their last play", result, agreed, checked);
+                }
+            } else {
+                error!("unable to load week {} for year 0 ({}) in league {}", WEEK, year, LEAGUE_NAME);
+                done = false;
+            }
+        } else {
+            error!("unable to find year 0 in league {}", LEAGUE_NAME);
+            done = false;
+        }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
+    }
+
+    assert!(done);
+}
+
+#[test]
+fn week_box_scores ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+    const YEAR_SELECTION: usize = 0;
+    const WEEK: u8 = 1;
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(mut league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        league.load_league_data();
+
+        if let (Some(year), Some(players)) = (league.get_year(YEAR_SELECTION), league.get_players()) {
+            if let Some(week) = league.get_week(year, WEEK) {
+                for game in week.games.iter() {
+                    debug!("{}", game.environment());
+                    let box_score = BoxScore9::new(game);
+                    debug!("{}", box_score.render(Some(&players)));
+
+                    // player lines come from the plays, team totals from the stats at the end of the game
+                    for (team, lines) in box_score.teams.iter().zip(box_score.players.iter()) {
+                        assert_eq!(lines.passing.values().map(|l| l.attempts).sum::<u32>(), team.pass_attempts, "{} pass attempts", team.short);
+                        assert_eq!(lines.passing.values().map(|l| l.completions).sum::<u32>(), team.pass_completions, "{} completions", team.short);
+                        assert_eq!(lines.rushing.values().map(|l| l.attempts).sum::<u32>(), team.rush_attempts, "{} rush attempts", team.short);
+                    }
+                    debug!("{}", PlayByPlay9::new(game).with_players(&players));
+
+                    // the game files keep no final score, so check the reconstructed one against the red zone totals at the end of the game
+                    let (home, away) = game.final_score();
+                    debug!("{} {} - {} {}\n{}", game.home_team().short(), home, game.away_team().short(), away, game.scoring_summary());
+                    for (team, points) in [(0, home), (1, away)] {
+                        let possession = game.possession_stats(team);
+                        assert!(points >= 6 * possession.red_zone_touchdowns() + 3 * possession.red_zone_field_goals(), "{} scored {}", game.team(team).short(), points);
+                    }
+                    let issues = game.check_score();
+                    for issue in issues.iter() {
+                        error!("{}", issue);
+                    }
+                    assert!(issues.is_empty());
+
+                    for team in 0..2 {
+                        for drive in game.drives(team) {
+                            debug!("{} {}", game.team(team).short(), drive);
+                        }
+                        debug!("{} {}", game.team(team).short(), game.drive_summary(team));
+                    }
+                }
+            } else {
+                error!("unable to load week {} for year 0 ({}) in league {}", WEEK, year, LEAGUE_NAME);
+                done = false;
+            }
+        } else {
+            error!("unable to find year 0 or players in league {}", LEAGUE_NAME);
+            done = false;
+        }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
+    }
+
+    assert!(done);
+}
+
+#[test]
+fn season_team_summaries ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+    const YEAR_SELECTION: usize = 0;
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(mut league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        league.load_league_data();
+
+        if let Some(year) = league.get_year(YEAR_SELECTION) {
+            if let Some(weeks_list) = league.get_weeks_list_for_year(year) {
+                let weeks: Vec<_> = weeks_list.into_iter().filter_map(|week_num| league.get_week(year, week_num)).collect();
+                if let Some(game) = weeks.first().and_then(|week| week.games.first()) {
+                    for game_team in 0..2 {
+                        let summary = TeamGameSummary9::season(&weeks, game.team(game_team).number());
+                        debug!("{}", summary);
+
+                        let offense = ScoutingReport9::offense(&weeks, game.team(game_team).number());
+                        debug!("{}", offense);
+                        debug!("{}", offense.to_csv());
+
+                        let defense = ScoutingReport9::defense(&weeks, game.team(game_team).number());
+                        debug!("{}", defense);
+                        debug!("{}", defense.to_csv());
+
+                        // every play counted has to land in a row of every table
+                        for report in [&offense, &defense] {
+                            for table in &report.tables {
+                                assert_eq!(table.rows.iter().map(|(bucket, _)| table.total(bucket)).sum::<u32>(), report.plays, "{} by {}", table.category, table.situation);
+                            }
+                        }
+                    }
+                }
+
+                debug!("{}", PenaltyReport9::from_weeks(&weeks).to_text());
+
+                debug!("{}", BlitzAnalysis9::league(&weeks));
+                for analysis in BlitzAnalysis9::by_defense(&weeks).values() {
+                    debug!("{}", analysis.to_csv());
+                }
+            } else {
+                error!("unable to find weeks in year 0 ({}) in league {}", year, LEAGUE_NAME);
+                done = false;
+            }
+        } else {
+            error!("unable to find year 0 in league {}", LEAGUE_NAME);
+            done = false;
         }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
     }
 
+    assert!(done);
+}
+
+#[test]
+fn season_reports ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+    const YEAR_SELECTION: usize = 0;
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(mut league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        league.load_league_data();
+
+        if let Some(year) = league.get_year(YEAR_SELECTION) {
+            if let Some(report) = league.injury_report(year) {
+                debug!("{} injuries in {}", report.injuries.len(), year);
+                debug!("{}", report);
+                debug!("{}", report.to_csv());
+
+                if let Some(attendance) = league.attendance_report(year) {
+                    debug!("{}", attendance);
+                    debug!("{}", attendance.to_csv());
+                }
+            } else {
+                error!("unable to load weeks for year 0 ({}) in league {}", year, LEAGUE_NAME);
+                done = false;
+            }
+        } else {
+            error!("unable to find year 0 in league {}", LEAGUE_NAME);
+            done = false;
+        }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
+    }
+
+    assert!(done);
+}
+
+#[test]
+fn expected_points ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+    const YEAR_SELECTION: usize = 0;
+    const WEEK: u8 = 1;
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(mut league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        league.load_league_data();
+
+        let model = league.fit_expected_points();
+        debug!("{}", model);
+
+        let path = std::env::temp_dir().join(format!("fofdata_expected_points_{}.csv", std::process::id()));
+        model.save(&path).expect("unable to save model");
+        assert_eq!(ExpectedPoints9::load(&path), Some(model.clone()));
+        let _ = std::fs::remove_file(&path);
+
+        if let Some(week) = league.get_year(YEAR_SELECTION).and_then(|year| league.get_week(year, WEEK)) {
+            for game in week.games.iter() {
+                let plays: Vec<_> = game.plays().collect();
+                for value in game.play_values(&model) {
+                    debug!("{:+.2} {}", value.epa(), plays[value.play_index].play);
+                }
+            }
+        } else {
+            error!("unable to load week {} for year 0 in league {}", WEEK, LEAGUE_NAME);
+            done = false;
+        }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
+    }
+
+    assert!(done);
+}
+
+#[test]
+fn win_probability ( ) {
+    common::setup_logger(module_path!()).expect("log did not start");
+    info!("Starting");
+
+    const LEAGUE_NAME: &str = "New_Trial";
+    const YEAR_SELECTION: usize = 0;
+    const WEEK: u8 = 1;
+
+    let mut done = true;
+
+    let league_info = fofdata::find_leagues_9();
+
+    if let Some(mut league) = league_info.get_league_info(LEAGUE_NAME) {
+        info!("processing league: {}", LEAGUE_NAME);
+        league.load_league_data();
+
+        let model = league.fit_win_probability();
+        debug!("{}", model);
+
+        let path = std::env::temp_dir().join(format!("fofdata_win_probability_{}.csv", std::process::id()));
+        model.save(&path).expect("unable to save model");
+        assert_eq!(WinProbability9::load(&path), Some(model));
+        let _ = std::fs::remove_file(&path);
+
+        if let Some(week) = league.get_year(YEAR_SELECTION).and_then(|year| league.get_week(year, WEEK)) {
+            for game in week.games.iter() {
+                let plays: Vec<_> = game.plays().collect();
+                for wp in game.win_probability(&model) {
+                    assert!((0.0..=1.0).contains(&wp.home_before));
+                    debug!("{} {}", wp, plays[wp.play_index].play);
+                }
+                debug!("{:?}", game.win_probability_chart(&model));
+            }
+        } else {
+            error!("unable to load week {} for year 0 in league {}", WEEK, LEAGUE_NAME);
+            done = false;
+        }
+    } else {
+        error!("unable to find league {}", LEAGUE_NAME);
+        done = false;
+    }
+
+    assert!(done);
+}
+
+fn show_game ( game: &Game9Data ) {
+    debug!("starting game: {}", game.sections.first().unwrap());
+    for line in PlayByPlay9::new(game).style(PlayByPlayStyle9::Verbose).lines() {
+        debug!("{}", line);
+    }
+    debug!("ending game");
 }